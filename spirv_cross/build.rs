@@ -7,6 +7,11 @@ fn main() {
         }
     }
 
+    // Only the plain reflection types are available without the backend
+    if !cfg!(feature = "backend") {
+        return;
+    }
+
     let target_vendor = std::env::var("CARGO_CFG_TARGET_VENDOR");
     let is_apple = target_vendor.is_ok() && target_vendor.unwrap() == "apple";
 
@@ -53,5 +58,8 @@ fn main() {
         .file("src/vendor/SPIRV-Cross/spirv_msl.cpp")
         .flag("-DSPIRV_CROSS_WRAPPER_MSL");
 
+    #[cfg(feature = "debug-ir")]
+    build.flag("-DSPIRV_CROSS_WRAPPER_DEBUG_IR");
+
     build.compile("spirv-cross-rust-wrapper");
 }
@@ -2944,6 +2944,8 @@ pub mod root {
         pub force_storage_buffer_as_uav: bool,
         pub nonwritable_uav_texture_as_srv: bool,
         pub force_zero_initialized_variables: bool,
+        pub flatten_matrix_vertex_input_semantics: bool,
+        pub preserve_structured_buffers: bool,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
@@ -2968,6 +2970,18 @@ pub mod root {
         pub force_native_arrays: bool,
         pub force_zero_initialized_variables: bool,
         pub force_active_argument_buffer_resources: bool,
+        pub multiview: bool,
+        pub multiview_layered_rendering: bool,
+        pub view_mask_buffer_index: u32,
+        pub device_index: u32,
+        pub texture_buffer_native: bool,
+        pub sample_dref_lod_array_as_grad: bool,
+        pub ios_support_base_vertex_instance: bool,
+        pub emulate_subgroups: bool,
+        pub fixed_subgroup_size: u32,
+        pub emulate_cube_array: bool,
+        pub r32ui_linear_texture_alignment: u32,
+        pub r32ui_alignment_constant_id: u32,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
@@ -2989,6 +3003,14 @@ pub mod root {
         pub emit_line_directives: bool,
         pub enable_storage_image_qualifier_deduction: bool,
         pub force_zero_initialized_variables: bool,
+        pub force_flattened_io_blocks: bool,
+    }
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+    pub struct ScCommonOptions {
+        pub flatten_multidimensional_arrays: bool,
+        pub enable_row_major_load_workaround: bool,
+        pub ovr_multiview_view_count: u32,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
@@ -3056,12 +3078,23 @@ pub mod root {
         pub array_size: usize,
         pub storage: root::spv::StorageClass,
         pub image: root::spirv_cross::SPIRType_ImageType,
+        pub pointer: bool,
+        pub forward_pointer: bool,
+        pub parent_type: u32,
     }
     extern "C" {
         pub fn sc_internal_get_latest_exception_message(
             message: *mut *const ::std::os::raw::c_char,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_parse(
+            ir: *const u32,
+            size: usize,
+            has_entry_points: *mut bool,
+            addressing_model: *mut root::spv::AddressingModel,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_hlsl_new(
             compiler: *mut *mut root::ScInternalCompilerHlsl,
@@ -3082,6 +3115,18 @@ pub mod root {
             count: usize,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_hlsl_get_shader_model(
+            compiler: *const root::ScInternalCompilerHlsl,
+            shader_model: *mut i32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_hlsl_set_shader_model(
+            compiler: *const root::ScInternalCompilerHlsl,
+            shader_model: i32,
+        ) -> root::ScInternalResult;
+    }
     #[repr(C)]
     #[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
     pub struct ScMslConstSamplerMapping {
@@ -3108,6 +3153,13 @@ pub mod root {
             is_rasterization_disabled: *mut bool,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_get_spirv(
+            compiler: *const root::ScInternalCompilerMsl,
+            words: *mut *const u32,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_msl_compile(
             compiler: *const root::ScInternalCompilerBase,
@@ -3127,6 +3179,65 @@ pub mod root {
             result: *mut u32,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_set_argument_buffer_device_address_space(
+            compiler: *const root::ScInternalCompilerMsl,
+            desc_set: u32,
+            device_storage: bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_add_dynamic_buffer(
+            compiler: *const root::ScInternalCompilerMsl,
+            desc_set: u32,
+            binding: u32,
+            index: u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_add_inline_uniform_block(
+            compiler: *const root::ScInternalCompilerMsl,
+            desc_set: u32,
+            binding: u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_set_combined_sampler_suffix(
+            compiler: *const root::ScInternalCompilerMsl,
+            suffix: *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_get_combined_sampler_suffix(
+            compiler: *const root::ScInternalCompilerMsl,
+            suffix: *mut *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_buffer_requires_array_length(
+            compiler: *const root::ScInternalCompilerMsl,
+            id: u32,
+            result: *mut bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_set_enabled_fragment_outputs(
+            compiler: *const root::ScInternalCompilerMsl,
+            mask: u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_get_version(
+            compiler: *const root::ScInternalCompilerMsl,
+            version: *mut u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_msl_set_version(
+            compiler: *const root::ScInternalCompilerMsl,
+            version: u32,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_glsl_new(
             compiler: *mut *mut root::ScInternalCompilerGlsl,
@@ -3140,6 +3251,27 @@ pub mod root {
             options: *const root::ScGlslCompilerOptions,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_glsl_get_version(
+            compiler: *const root::ScInternalCompilerGlsl,
+            version: *mut u32,
+            es: *mut bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_glsl_set_version(
+            compiler: *const root::ScInternalCompilerGlsl,
+            version: u32,
+            es: bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_glsl_get_spirv(
+            compiler: *const root::ScInternalCompilerGlsl,
+            words: *mut *const u32,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_glsl_build_combined_image_samplers(
             compiler: *const root::ScInternalCompilerBase,
@@ -3164,6 +3296,38 @@ pub mod root {
             id: u32,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_glsl_get_required_extensions(
+            compiler: *const root::ScInternalCompilerBase,
+            extensions: *mut *mut *const ::std::os::raw::c_char,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_debug_get_id(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            kind: *mut *const ::std::os::raw::c_char,
+            type_id: *mut u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_debug_get_decorations(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            decorations: *mut *mut root::spv::Decoration,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_debug_get_member_decorations(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            index: u32,
+            decorations: *mut *mut root::spv::Decoration,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_decoration(
             compiler: *const root::ScInternalCompilerBase,
@@ -3172,6 +3336,23 @@ pub mod root {
             decoration: root::spv::Decoration,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_has_decoration(
+            compiler: *const root::ScInternalCompilerBase,
+            result: *mut bool,
+            id: u32,
+            decoration: root::spv::Decoration,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_binary_offset_for_decoration(
+            compiler: *const root::ScInternalCompilerBase,
+            result: *mut bool,
+            word_offset: *mut u32,
+            id: u32,
+            decoration: root::spv::Decoration,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_set_decoration(
             compiler: *const root::ScInternalCompilerBase,
@@ -3187,6 +3368,31 @@ pub mod root {
             decoration: root::spv::Decoration,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_decoration_string(
+            compiler: *const root::ScInternalCompilerBase,
+            result: *mut *const ::std::os::raw::c_char,
+            id: u32,
+            decoration: root::spv::Decoration,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_member_decoration_string(
+            compiler: *const root::ScInternalCompilerBase,
+            result: *mut *const ::std::os::raw::c_char,
+            id: u32,
+            index: u32,
+            decoration: root::spv::Decoration,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_set_decoration_string(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            decoration: root::spv::Decoration,
+            argument: *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_name(
             compiler: *const root::ScInternalCompilerBase,
@@ -3209,6 +3415,11 @@ pub mod root {
             name: *const ::std::os::raw::c_char,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_strip_debug_info(
+            compiler: *const root::ScInternalCompilerBase,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_entry_points(
             compiler: *const root::ScInternalCompilerBase,
@@ -3224,6 +3435,14 @@ pub mod root {
             size: *mut usize,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_buffer_block_decorations(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            decorations: *mut *mut root::spv::Decoration,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_cleansed_entry_point_name(
             compiler: *const root::ScInternalCompilerBase,
@@ -3232,12 +3451,33 @@ pub mod root {
             compiled_entry_point_name: *mut *const ::std::os::raw::c_char,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_remapped_declared_block_name(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            name: *mut *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_source_file_name(
+            compiler: *const root::ScInternalCompilerBase,
+            name: *mut *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_shader_resources(
             compiler: *const root::ScInternalCompilerBase,
             shader_resources: *mut root::ScShaderResources,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_shader_resources_for_active_variables(
+            compiler: *const root::ScInternalCompilerBase,
+            shader_resources: *mut root::ScShaderResources,
+            ids: *const u32,
+            size: usize,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_specialization_constants(
             compiler: *const root::ScInternalCompilerBase,
@@ -3253,6 +3493,39 @@ pub mod root {
             constant_low_bits: u32,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_constants(
+            compiler: *const root::ScInternalCompilerBase,
+            ids: *mut *mut u32,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_ids_for_type(
+            compiler: *const root::ScInternalCompilerBase,
+            type_: u32,
+            ids: *mut *mut u32,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_set_composite_constant(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            values: *const u64,
+            size: usize,
+            component_count: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_scalar_constant(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            is_scalar: *mut bool,
+            constant_high_bits: *mut u32,
+            constant_low_bits: *mut u32,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_type(
             compiler: *const root::ScInternalCompilerBase,
@@ -3260,6 +3533,13 @@ pub mod root {
             spirv_type: *mut *const root::ScType,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_variable_type_id(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            type_id: *mut u32,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_member_name(
             compiler: *const root::ScInternalCompilerBase,
@@ -3286,6 +3566,14 @@ pub mod root {
             argument: u32,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_member_count(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            is_struct: *mut bool,
+            count: *mut u32,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_declared_struct_size(
             compiler: *const root::ScInternalCompilerBase,
@@ -3293,6 +3581,14 @@ pub mod root {
             result: *mut u32,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_declared_struct_size_runtime_array(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            array_size: u32,
+            result: *mut u32,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_declared_struct_member_size(
             compiler: *const root::ScInternalCompilerBase,
@@ -3301,6 +3597,20 @@ pub mod root {
             result: *mut u32,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_id_bound(
+            compiler: *const root::ScInternalCompilerBase,
+            result: *mut u32,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_set_interface_variable_name(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            location: u32,
+            name: *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_rename_interface_variable(
             compiler: *const root::ScInternalCompilerBase,
@@ -3323,6 +3633,60 @@ pub mod root {
             execution_model: root::spv::ExecutionModel,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_set_common_options(
+            compiler: *const root::ScInternalCompilerBase,
+            options: *const root::ScCommonOptions,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_execution_model(
+            compiler: *const root::ScInternalCompilerBase,
+            execution_model: *mut root::spv::ExecutionModel,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_entry_point_name(
+            compiler: *const root::ScInternalCompilerBase,
+            name: *mut *const ::std::os::raw::c_char,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_declared_capabilities(
+            compiler: *const root::ScInternalCompilerBase,
+            capabilities: *mut *mut root::spv::Capability,
+            size: *mut usize,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_addressing_model(
+            compiler: *const root::ScInternalCompilerBase,
+            addressing_model: *mut root::spv::AddressingModel,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_has_execution_mode(
+            compiler: *const root::ScInternalCompilerBase,
+            mode: root::spv::ExecutionMode,
+            result: *mut bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_has_active_builtin(
+            compiler: *const root::ScInternalCompilerBase,
+            builtin: root::spv::BuiltIn,
+            storage: root::spv::StorageClass,
+            result: *mut bool,
+        ) -> root::ScInternalResult;
+    }
+    extern "C" {
+        pub fn sc_internal_compiler_get_execution_mode_argument(
+            compiler: *const root::ScInternalCompilerBase,
+            mode: root::spv::ExecutionMode,
+            index: u32,
+            result: *mut u32,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_compile(
             compiler: *const root::ScInternalCompilerBase,
@@ -3334,6 +3698,13 @@ pub mod root {
             compiler: *mut root::ScInternalCompilerBase,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_get_variable_storage_class(
+            compiler: *const root::ScInternalCompilerBase,
+            id: u32,
+            storage_class: *mut root::spv::StorageClass,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_compiler_get_active_interface_variables(
             compiler: *const root::ScInternalCompilerBase,
@@ -3341,6 +3712,13 @@ pub mod root {
             size: *mut usize,
         ) -> root::ScInternalResult;
     }
+    extern "C" {
+        pub fn sc_internal_compiler_set_enabled_interface_variables(
+            compiler: *const root::ScInternalCompilerBase,
+            ids: *const u32,
+            size: usize,
+        ) -> root::ScInternalResult;
+    }
     extern "C" {
         pub fn sc_internal_free_pointer(
             pointer: *mut ::std::os::raw::c_void,
@@ -2919,6 +2919,8 @@ pub mod root {
         pub force_storage_buffer_as_uav: bool,
         pub nonwritable_uav_texture_as_srv: bool,
         pub force_zero_initialized_variables: bool,
+        pub flatten_matrix_vertex_input_semantics: bool,
+        pub preserve_structured_buffers: bool,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -2943,6 +2945,11 @@ pub mod root {
         pub force_native_arrays: bool,
         pub force_zero_initialized_variables: bool,
         pub force_active_argument_buffer_resources: bool,
+        pub multiview: bool,
+        pub multiview_layered_rendering: bool,
+        pub view_mask_buffer_index: u32,
+        pub device_index: u32,
+        pub texture_buffer_native: bool,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -2964,6 +2971,7 @@ pub mod root {
         pub emit_line_directives: bool,
         pub enable_storage_image_qualifier_deduction: bool,
         pub force_zero_initialized_variables: bool,
+        pub force_flattened_io_blocks: bool,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -3031,6 +3039,9 @@ pub mod root {
         pub array_size: usize,
         pub storage: root::spv::StorageClass,
         pub image: root::spirv_cross::SPIRType_ImageType,
+        pub pointer: bool,
+        pub forward_pointer: bool,
+        pub parent_type: u32,
     }
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
@@ -1,11 +1,17 @@
 //! Raw compiler bindings for SPIRV-Cross.
 use crate::{bindings as br, spirv::ImageType};
-use crate::ptr_util::{read_from_ptr, read_into_vec_from_ptr, read_string_from_ptr};
+use crate::ptr_util::{
+    read_cow_from_ptr, read_from_ptr, read_into_vec_from_ptr, read_string_from_ptr,
+};
 use crate::spirv::{self, Decoration, Type};
 use crate::ErrorCode;
-use std::collections::HashSet;
+use std::any::Any;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::ffi::CString;
 use std::os::raw::c_void;
+use std::rc::Rc;
 use std::{mem::MaybeUninit, ptr};
 
 impl spirv::ImageType {
@@ -23,18 +29,30 @@ impl spirv::ImageType {
 }
 
 impl spirv::Type {
-    pub(crate) fn from_raw(
-        ty: br::spirv_cross::SPIRType_BaseType,
-        vecsize: u32,
-        columns: u32,
-        member_types: Vec<u32>,
-        array: Vec<u32>,
-        array_size_literal: Vec<bool>,
-        image: br::spirv_cross::SPIRType_ImageType,
-    ) -> Self {
+    /// Reads a type from its raw form, which must have been returned by
+    /// `sc_internal_compiler_get_type` and not yet freed.
+    pub(crate) unsafe fn from_raw(raw: &br::ScType) -> Self {
+        let array = read_into_vec_from_ptr(raw.array, raw.array_size);
+        let array_size_literal = read_into_vec_from_ptr(raw.array_size_literal, raw.array_size);
+        if (raw.pointer || raw.forward_pointer)
+            && raw.storage == spirv::StorageClass::PhysicalStorageBuffer
+        {
+            return Type::Pointer {
+                pointee_type: raw.parent_type,
+                storage_class: raw.storage,
+                array,
+                array_size_literal,
+            };
+        }
+
+        let width = raw.width;
+        let vecsize = raw.vecsize;
+        let columns = raw.columns;
+        let parent_type = raw.parent_type;
+        let image = raw.image;
         use crate::bindings::root::spirv_cross::SPIRType_BaseType as B;
         use crate::spirv::Type::*;
-        match ty {
+        match raw.type_ {
             B::Unknown => Unknown,
             B::Void => Void,
             B::Boolean => Boolean {
@@ -45,63 +63,95 @@ impl spirv::Type {
             },
             B::Char => Char { array, array_size_literal },
             B::Int => Int {
+                width,
                 vecsize,
                 columns,
                 array,
                 array_size_literal,
             },
             B::UInt => UInt {
+                width,
                 vecsize,
                 columns,
                 array,
                 array_size_literal,
             },
-            B::Int64 => Int64 { vecsize, array, array_size_literal },
-            B::UInt64 => UInt64 { vecsize, array, array_size_literal },
+            B::Int64 => Int64 { width, vecsize, array, array_size_literal },
+            B::UInt64 => UInt64 { width, vecsize, array, array_size_literal },
             B::AtomicCounter => AtomicCounter { array, array_size_literal },
             B::Half => Half {
+                width,
                 vecsize,
                 columns,
                 array,
                 array_size_literal,
             },
             B::Float => Float {
+                width,
                 vecsize,
                 columns,
                 array,
                 array_size_literal,
             },
             B::Double => Double {
+                width,
                 vecsize,
                 columns,
                 array,
                 array_size_literal,
             },
             B::Struct => Struct {
-                member_types,
+                member_types: read_into_vec_from_ptr(raw.member_types, raw.member_types_size),
                 array,
                 array_size_literal,
             },
             B::Image => Image { array, array_size_literal, image: ImageType::from_raw(image) },
-            B::SampledImage => SampledImage { array, array_size_literal, image: ImageType::from_raw(image) },
+            B::SampledImage => SampledImage {
+                array,
+                array_size_literal,
+                comparison: image.depth,
+                image: ImageType::from_raw(image),
+            },
             B::Sampler => Sampler { array, array_size_literal },
-            B::SByte => SByte { vecsize, array, array_size_literal },
-            B::UByte => UByte { vecsize, array, array_size_literal },
-            B::Short => Short { vecsize, array, array_size_literal },
-            B::UShort => UShort { vecsize, array, array_size_literal },
-            B::ControlPointArray => ControlPointArray,
-            B::AccelerationStructure => AccelerationStructure,
-            B::RayQuery => RayQuery,
-            B::Interpolant => Interpolant,
+            B::SByte => SByte { width, vecsize, array, array_size_literal },
+            B::UByte => UByte { width, vecsize, array, array_size_literal },
+            B::Short => Short { width, vecsize, array, array_size_literal },
+            B::UShort => UShort { width, vecsize, array, array_size_literal },
+            B::ControlPointArray => ControlPointArray {
+                element_type: parent_type,
+                array,
+                array_size_literal,
+            },
+            B::AccelerationStructure => AccelerationStructure { array, array_size_literal },
+            B::RayQuery => RayQuery { array, array_size_literal },
+            B::Interpolant => Interpolant {
+                element_type: parent_type,
+                array,
+                array_size_literal,
+            },
         }
     }
 }
 
-#[derive(Debug, Clone)]
+/// Owns the underlying SPIRV-Cross compiler, which is deleted on drop. Not `Clone`, since
+/// copying the pointer would lead to a double free.
+#[derive(Debug)]
 pub struct Compiler<TTargetData> {
     pub(crate) sc_compiler: *mut br::ScInternalCompilerBase,
     pub(crate) target_data: TTargetData,
     pub(crate) has_been_compiled: bool,
+    /// Whether compiled output has its variable declarations annotated with their ids.
+    #[cfg(any(feature = "glsl", feature = "hlsl", feature = "msl"))]
+    pub(crate) annotate_ids: bool,
+    /// Types read by `get_type`. Types are immutable once parsed, so the cache lives as long
+    /// as the `Ast` and is never invalidated.
+    pub(crate) type_cache: RefCell<HashMap<u32, Type>>,
+    /// Output of `Ast::compile_cached`, keyed by the hash of the options it was compiled with.
+    /// The options are kept alongside to rule out hash collisions.
+    pub(crate) compile_cache: HashMap<u64, (Box<dyn Any>, Rc<String>)>,
+    /// The interface variables last passed to `set_enabled_interface_variables`, or `None` if
+    /// all interface variables are enabled. SPIRV-Cross has no getter for them.
+    pub(crate) enabled_interface_variables: Option<HashSet<u32>>,
 }
 
 impl<TTargetData> Compiler<TTargetData> {
@@ -115,10 +165,48 @@ impl<TTargetData> Compiler<TTargetData> {
             ));
             let shader = read_string_from_ptr(shader_ptr)?;
             check!(br::sc_internal_free_pointer(shader_ptr as *mut c_void));
+            if self.annotate_ids {
+                return self.annotate_shader_ids(&shader);
+            }
             Ok(shader)
         }
     }
 
+    /// Appends an `/* id=N */` comment to the declaration of each global variable in `shader`.
+    /// Declarations are found at the top level by the name SPIRV-Cross emits for the
+    /// variable, so variables it renames or flattens are left unannotated, as are function
+    /// local variables and those declared as struct members, such as MSL stage outputs.
+    #[cfg(any(feature = "glsl", feature = "hlsl", feature = "msl"))]
+    pub(crate) fn annotate_shader_ids(&self, shader: &str) -> Result<String, ErrorCode> {
+        let mut insertions = Vec::new();
+        for id in self.get_ids_for_type(spirv::IdType::Variable)? {
+            if self.get_variable_storage_class(id)? == spirv::StorageClass::Function {
+                continue;
+            }
+            let name = self.get_name_cow(id)?;
+            let name = if name.is_empty() {
+                format!("_{}", id)
+            } else {
+                name.into_owned()
+            };
+            if let Some(offset) = find_declaration(shader, &name) {
+                insertions.push((offset, id));
+            }
+        }
+        insertions.sort_unstable();
+        insertions.dedup_by_key(|&mut (offset, _)| offset);
+
+        let mut annotated = String::with_capacity(shader.len());
+        let mut last = 0;
+        for (offset, id) in insertions {
+            annotated.push_str(&shader[last..offset]);
+            annotated.push_str(&format!(" /* id={} */", id));
+            last = offset;
+        }
+        annotated.push_str(&shader[last..]);
+        Ok(annotated)
+    }
+
     pub fn get_decoration(&self, id: u32, decoration: spirv::Decoration) -> Result<u32, ErrorCode> {
         let mut result = 0;
         unsafe {
@@ -132,7 +220,43 @@ impl<TTargetData> Compiler<TTargetData> {
         Ok(result)
     }
 
-    pub fn get_name(&mut self, id: u32) -> Result<String, ErrorCode> {
+    pub fn has_decoration(&self, id: u32, decoration: spirv::Decoration) -> Result<bool, ErrorCode> {
+        let mut result = false;
+        unsafe {
+            check!(br::sc_internal_compiler_has_decoration(
+                self.sc_compiler,
+                &mut result,
+                id,
+                decoration,
+            ));
+        }
+        Ok(result)
+    }
+
+    pub fn get_binary_offset_for_decoration(
+        &self,
+        id: u32,
+        decoration: spirv::Decoration,
+    ) -> Result<Option<u32>, ErrorCode> {
+        let mut result = false;
+        let mut word_offset = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_get_binary_offset_for_decoration(
+                self.sc_compiler,
+                &mut result,
+                &mut word_offset,
+                id,
+                decoration,
+            ));
+        }
+        Ok(if result { Some(word_offset) } else { None })
+    }
+
+    pub fn get_name(&self, id: u32) -> Result<String, ErrorCode> {
+        self.get_name_cow(id).map(Cow::into_owned)
+    }
+
+    pub fn get_name_cow(&self, id: u32) -> Result<Cow<'static, str>, ErrorCode> {
         unsafe {
             let mut name_ptr = ptr::null();
             check!(br::sc_internal_compiler_get_name(
@@ -140,7 +264,7 @@ impl<TTargetData> Compiler<TTargetData> {
                 id,
                 &mut name_ptr,
             ));
-            let name = read_string_from_ptr(name_ptr)?;
+            let name = read_cow_from_ptr(name_ptr)?;
             check!(br::sc_internal_free_pointer(name_ptr as *mut c_void));
             Ok(name)
         }
@@ -157,7 +281,7 @@ impl<TTargetData> Compiler<TTargetData> {
                         name.as_ptr(),
                     ));
                 }
-                _ => return Err(ErrorCode::Unhandled),
+                Err(error) => return Err(error.into()),
             }
         }
         Ok(())
@@ -175,12 +299,20 @@ impl<TTargetData> Compiler<TTargetData> {
                         name.as_ptr(),
                     ));
                 }
-                _ => return Err(ErrorCode::Unhandled),
+                Err(error) => return Err(error.into()),
             }
         }
         Ok(())
     }
 
+    pub fn strip_debug_info(&mut self) -> Result<(), ErrorCode> {
+        unsafe {
+            check!(br::sc_internal_compiler_strip_debug_info(self.sc_compiler));
+        }
+
+        Ok(())
+    }
+
     pub fn unset_decoration(
         &mut self,
         id: u32,
@@ -215,6 +347,65 @@ impl<TTargetData> Compiler<TTargetData> {
         Ok(())
     }
 
+    pub fn get_decoration_string(
+        &self,
+        id: u32,
+        decoration: spirv::Decoration,
+    ) -> Result<String, ErrorCode> {
+        unsafe {
+            let mut result_ptr = ptr::null();
+            check!(br::sc_internal_compiler_get_decoration_string(
+                self.sc_compiler,
+                &mut result_ptr,
+                id,
+                decoration,
+            ));
+            let result = read_string_from_ptr(result_ptr)?;
+            check!(br::sc_internal_free_pointer(result_ptr as *mut c_void));
+            Ok(result)
+        }
+    }
+
+    pub fn get_member_decoration_string(
+        &self,
+        id: u32,
+        index: u32,
+        decoration: spirv::Decoration,
+    ) -> Result<String, ErrorCode> {
+        unsafe {
+            let mut result_ptr = ptr::null();
+            check!(br::sc_internal_compiler_get_member_decoration_string(
+                self.sc_compiler,
+                &mut result_ptr,
+                id,
+                index,
+                decoration,
+            ));
+            let result = read_string_from_ptr(result_ptr)?;
+            check!(br::sc_internal_free_pointer(result_ptr as *mut c_void));
+            Ok(result)
+        }
+    }
+
+    pub fn set_decoration_string(
+        &mut self,
+        id: u32,
+        decoration: spirv::Decoration,
+        argument: &str,
+    ) -> Result<(), ErrorCode> {
+        let argument = CString::new(argument)?;
+        unsafe {
+            check!(br::sc_internal_compiler_set_decoration_string(
+                self.sc_compiler,
+                id,
+                decoration,
+                argument.as_ptr(),
+            ));
+        }
+
+        Ok(())
+    }
+
     pub fn get_entry_points(&self) -> Result<Vec<spirv::EntryPoint>, ErrorCode> {
         let mut entry_points_raw = ptr::null_mut();
         let mut entry_points_raw_length = 0;
@@ -290,6 +481,29 @@ impl<TTargetData> Compiler<TTargetData> {
         }
     }
 
+    pub fn get_buffer_block_decorations(
+        &self,
+        id: u32,
+    ) -> Result<Vec<spirv::Decoration>, ErrorCode> {
+        let mut decorations_raw = ptr::null_mut();
+        let mut decorations_raw_length = 0;
+
+        unsafe {
+            check!(br::sc_internal_compiler_get_buffer_block_decorations(
+                self.sc_compiler,
+                id,
+                &mut decorations_raw,
+                &mut decorations_raw_length,
+            ));
+
+            let decorations = read_into_vec_from_ptr(decorations_raw, decorations_raw_length);
+
+            check!(br::sc_internal_free_pointer(decorations_raw as *mut c_void));
+
+            Ok(decorations)
+        }
+    }
+
     pub fn get_cleansed_entry_point_name(
         &self,
         entry_point_name: &str,
@@ -309,7 +523,37 @@ impl<TTargetData> Compiler<TTargetData> {
                 check!(br::sc_internal_free_pointer(cleansed_ptr as *mut c_void));
                 Ok(cleansed)
             },
-            _ => Err(ErrorCode::Unhandled),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn get_remapped_declared_block_name(&self, id: u32) -> Result<String, ErrorCode> {
+        let mut name_ptr = ptr::null();
+        unsafe {
+            check!(br::sc_internal_compiler_get_remapped_declared_block_name(
+                self.sc_compiler,
+                id,
+                &mut name_ptr,
+            ));
+            let name = read_string_from_ptr(name_ptr)?;
+            check!(br::sc_internal_free_pointer(name_ptr as *mut c_void));
+            Ok(name)
+        }
+    }
+
+    pub fn get_source_file_name(&self) -> Result<Option<String>, ErrorCode> {
+        let mut name_ptr = ptr::null();
+        unsafe {
+            check!(br::sc_internal_compiler_get_source_file_name(
+                self.sc_compiler,
+                &mut name_ptr,
+            ));
+            if name_ptr.is_null() {
+                return Ok(None);
+            }
+            let name = read_string_from_ptr(name_ptr)?;
+            check!(br::sc_internal_free_pointer(name_ptr as *mut c_void));
+            Ok(Some(name))
         }
     }
 
@@ -362,7 +606,102 @@ impl<TTargetData> Compiler<TTargetData> {
         Ok(())
     }
 
-    pub fn get_type(&self, id: u32) -> Result<spirv::Type, ErrorCode> {
+    pub fn set_composite_constant(&self, id: u32, values: &[u64]) -> Result<(), ErrorCode> {
+        let mut component_count = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_set_composite_constant(
+                self.sc_compiler,
+                id,
+                values.as_ptr(),
+                values.len(),
+                &mut component_count,
+            ));
+        }
+
+        if component_count != values.len() {
+            return Err(ErrorCode::CompilationError(format!(
+                "expected {} values for composite constant, got {}",
+                component_count,
+                values.len()
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn get_constants(&self) -> Result<Vec<u32>, ErrorCode> {
+        unsafe {
+            let mut ids = ptr::null_mut();
+            let mut size = 0;
+            check!(br::sc_internal_compiler_get_constants(
+                self.sc_compiler,
+                &mut ids,
+                &mut size,
+            ));
+            let result = read_into_vec_from_ptr(ids, size);
+            check!(br::sc_internal_free_pointer(ids as *mut c_void));
+            Ok(result)
+        }
+    }
+
+    pub fn get_ids_for_type(&self, kind: spirv::IdType) -> Result<Vec<u32>, ErrorCode> {
+        let mut ids = ptr::null_mut();
+        let mut size = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_get_ids_for_type(
+                self.sc_compiler,
+                kind.as_raw(),
+                &mut ids,
+                &mut size,
+            ));
+            let result = read_into_vec_from_ptr(ids, size);
+            check!(br::sc_internal_free_pointer(ids as *mut c_void));
+            Ok(result)
+        }
+    }
+
+    pub fn get_scalar_constant(&self, id: u32) -> Result<u64, ErrorCode> {
+        let mut is_scalar = false;
+        let mut high_bits = 0;
+        let mut low_bits = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_get_scalar_constant(
+                self.sc_compiler,
+                id,
+                &mut is_scalar,
+                &mut high_bits,
+                &mut low_bits,
+            ));
+        }
+
+        if !is_scalar {
+            return Err(ErrorCode::CompilationError(String::from(
+                "composite constants are not supported",
+            )));
+        }
+
+        Ok((u64::from(high_bits) << 32) | u64::from(low_bits))
+    }
+
+    #[cfg(feature = "glsl")]
+    pub fn get_variable_type_id(&self, id: u32) -> Result<u32, ErrorCode> {
+        let mut type_id = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_get_variable_type_id(
+                self.sc_compiler,
+                id,
+                &mut type_id,
+            ));
+        }
+
+        Ok(type_id)
+    }
+
+    fn read_raw_type<R>(
+        &self,
+        id: u32,
+        read: impl FnOnce(&br::ScType) -> R,
+    ) -> Result<R, ErrorCode> {
         unsafe {
             let mut type_ptr = std::mem::zeroed();
 
@@ -373,11 +712,7 @@ impl<TTargetData> Compiler<TTargetData> {
             ));
 
             let raw = read_from_ptr::<br::ScType>(type_ptr);
-            let member_types = read_into_vec_from_ptr(raw.member_types, raw.member_types_size);
-            let array = read_into_vec_from_ptr(raw.array, raw.array_size);
-            let array_size_literal = read_into_vec_from_ptr(raw.array_size_literal, raw.array_size);
-            let image = raw.image;
-            let result = Type::from_raw(raw.type_, raw.vecsize, raw.columns, member_types, array, array_size_literal, image);
+            let result = read(&raw);
 
             if raw.member_types_size > 0 {
                 check!(br::sc_internal_free_pointer(
@@ -386,6 +721,9 @@ impl<TTargetData> Compiler<TTargetData> {
             }
             if raw.array_size > 0 {
                 check!(br::sc_internal_free_pointer(raw.array as *mut c_void));
+                check!(br::sc_internal_free_pointer(
+                    raw.array_size_literal as *mut c_void
+                ));
             }
             check!(br::sc_internal_free_pointer(type_ptr as *mut c_void));
 
@@ -393,7 +731,40 @@ impl<TTargetData> Compiler<TTargetData> {
         }
     }
 
+    pub fn get_type(&self, id: u32) -> Result<spirv::Type, ErrorCode> {
+        if let Some(ty) = self.type_cache.borrow().get(&id) {
+            return Ok(ty.clone());
+        }
+
+        let ty = self.read_raw_type(id, |raw| unsafe { Type::from_raw(raw) })?;
+        self.type_cache.borrow_mut().insert(id, ty.clone());
+        Ok(ty)
+    }
+
+    /// Gets the outermost array dimension of a type that isn't a pointer, as the length and
+    /// whether the length is a literal rather than the id of a constant.
+    pub fn get_outer_array_dimension(&self, id: u32) -> Result<Option<(u32, bool)>, ErrorCode> {
+        self.read_raw_type(id, |raw| unsafe {
+            if raw.pointer || raw.array_size == 0 {
+                return None;
+            }
+            let outer = raw.array_size - 1;
+            Some((
+                read_from_ptr(raw.array.add(outer)),
+                read_from_ptr(raw.array_size_literal.add(outer)),
+            ))
+        })
+    }
+
+    pub fn get_storage_class(&self, id: u32) -> Result<spirv::StorageClass, ErrorCode> {
+        self.read_raw_type(id, |raw| raw.storage)
+    }
+
     pub fn get_member_name(&self, id: u32, index: u32) -> Result<String, ErrorCode> {
+        self.get_member_name_cow(id, index).map(Cow::into_owned)
+    }
+
+    pub fn get_member_name_cow(&self, id: u32, index: u32) -> Result<Cow<'static, str>, ErrorCode> {
         unsafe {
             let mut name_ptr = ptr::null();
             check!(br::sc_internal_compiler_get_member_name(
@@ -402,7 +773,7 @@ impl<TTargetData> Compiler<TTargetData> {
                 index,
                 &mut name_ptr,
             ));
-            let name = read_string_from_ptr(name_ptr)?;
+            let name = read_cow_from_ptr(name_ptr)?;
             check!(br::sc_internal_free_pointer(name_ptr as *mut c_void));
             Ok(name)
         }
@@ -447,6 +818,55 @@ impl<TTargetData> Compiler<TTargetData> {
         Ok(())
     }
 
+    pub fn get_member_layout(&self, id: u32, index: u32) -> Result<spirv::MemberLayout, ErrorCode> {
+        let member_types = self.read_raw_type(id, |raw| unsafe {
+            read_into_vec_from_ptr(raw.member_types, raw.member_types_size)
+        })?;
+        let member_type_id = *member_types
+            .get(index as usize)
+            .ok_or(ErrorCode::Unhandled)?;
+        let (is_array, is_matrix) =
+            self.read_raw_type(member_type_id, |raw| (raw.array_size > 0, raw.columns > 1))?;
+
+        let array_stride = if is_array {
+            Some(self.get_decoration(member_type_id, Decoration::ArrayStride)?)
+        } else {
+            None
+        };
+        let matrix_stride = if is_matrix {
+            Some(self.get_member_decoration(id, index, Decoration::MatrixStride)?)
+        } else {
+            None
+        };
+
+        Ok(spirv::MemberLayout {
+            offset: self.get_member_decoration(id, index, Decoration::Offset)?,
+            size: self.get_declared_struct_member_size(id, index)?,
+            array_stride,
+            matrix_stride,
+            row_major: self.get_member_decoration(id, index, Decoration::RowMajor)? != 0,
+        })
+    }
+
+    pub fn get_member_count(&self, id: u32) -> Result<u32, ErrorCode> {
+        let mut is_struct = false;
+        let mut count = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_get_member_count(
+                self.sc_compiler,
+                id,
+                &mut is_struct,
+                &mut count,
+            ));
+        }
+        if !is_struct {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`id` is not a struct type",
+            )));
+        }
+        Ok(count)
+    }
+
     pub fn get_declared_struct_size(&self, id: u32) -> Result<u32, ErrorCode> {
         let mut result = 0;
         unsafe {
@@ -459,6 +879,25 @@ impl<TTargetData> Compiler<TTargetData> {
         Ok(result)
     }
 
+    pub fn get_declared_struct_size_runtime_array(
+        &self,
+        id: u32,
+        array_size: u32,
+    ) -> Result<u32, ErrorCode> {
+        let mut result = 0;
+        unsafe {
+            check!(
+                br::sc_internal_compiler_get_declared_struct_size_runtime_array(
+                    self.sc_compiler,
+                    id,
+                    array_size,
+                    &mut result,
+                )
+            );
+        }
+        Ok(result)
+    }
+
     pub fn get_declared_struct_member_size(&self, id: u32, index: u32) -> Result<u32, ErrorCode> {
         let mut result = 0;
         unsafe {
@@ -472,6 +911,17 @@ impl<TTargetData> Compiler<TTargetData> {
         Ok(result)
     }
 
+    pub fn get_id_bound(&self) -> Result<u32, ErrorCode> {
+        let mut result = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_get_id_bound(
+                self.sc_compiler,
+                &mut result,
+            ));
+        }
+        Ok(result)
+    }
+
     pub fn get_shader_resources(&self) -> Result<spirv::ShaderResources, ErrorCode> {
         unsafe {
             let mut shader_resources_raw = MaybeUninit::uninit();
@@ -479,93 +929,37 @@ impl<TTargetData> Compiler<TTargetData> {
                 self.sc_compiler,
                 shader_resources_raw.as_mut_ptr(),
             ));
-            let shader_resources_raw = shader_resources_raw.assume_init();
-
-            let fill_resources = |array_raw: &br::ScResourceArray| {
-                let resources = (0..array_raw.num)
-                    .map(|i| {
-                        let resource_raw = read_from_ptr::<br::ScResource>(array_raw.data.add(i));
-                        let name = read_string_from_ptr(resource_raw.name)?;
-                        check!(br::sc_internal_free_pointer(
-                            resource_raw.name as *mut c_void,
-                        ));
-
-                        Ok(spirv::Resource {
-                            id: resource_raw.id,
-                            type_id: resource_raw.type_id,
-                            base_type_id: resource_raw.base_type_id,
-                            name,
-                        })
-                    })
-                    .collect::<Result<Vec<_>, ErrorCode>>();
-
-                check!(br::sc_internal_free_pointer(array_raw.data as *mut c_void));
-
-                resources
-            };
+            read_shader_resources(shader_resources_raw.assume_init())
+        }
+    }
 
-            let fill_builtin_resources = |array_raw: &br::ScBuiltInResourceArray| {
-                let resources = (0..array_raw.num)
-                    .map(|i| {
-                        let resource_raw = read_from_ptr::<br::ScBuiltInResource>(array_raw.data.add(i));
-                        let name = read_string_from_ptr(resource_raw.resource.name)?;
-                        check!(br::sc_internal_free_pointer(
-                            resource_raw.resource.name as *mut c_void,
-                        ));
-
-                        Ok(spirv::BuiltInResource {
-                            builtin: spirv::BuiltIn::from_raw(resource_raw.builtin),
-                            value_type_id: resource_raw.value_type_id,
-                            resource: spirv::Resource {
-                                id: resource_raw.resource.id,
-                                type_id: resource_raw.resource.type_id,
-                                base_type_id: resource_raw.resource.base_type_id,
-                                name,
-                            },
-                        })
-                    })
-                    .collect::<Result<Vec<_>, ErrorCode>>();
-
-                check!(br::sc_internal_free_pointer(array_raw.data as *mut c_void));
-
-                resources
-            };
+    pub fn get_shader_resources_for_active_variables(
+        &self,
+        active_variables: &HashSet<u32>,
+    ) -> Result<spirv::ShaderResources, ErrorCode> {
+        let ids = active_variables.iter().cloned().collect::<Vec<_>>();
+        unsafe {
+            let mut shader_resources_raw = MaybeUninit::uninit();
+            check!(br::sc_internal_compiler_get_shader_resources_for_active_variables(
+                self.sc_compiler,
+                shader_resources_raw.as_mut_ptr(),
+                ids.as_ptr(),
+                ids.len(),
+            ));
+            read_shader_resources(shader_resources_raw.assume_init())
+        }
+    }
 
-            let uniform_buffers = fill_resources(&shader_resources_raw.uniform_buffers)?;
-            let storage_buffers = fill_resources(&shader_resources_raw.storage_buffers)?;
-            let stage_inputs = fill_resources(&shader_resources_raw.stage_inputs)?;
-            let stage_outputs = fill_resources(&shader_resources_raw.stage_outputs)?;
-            let subpass_inputs = fill_resources(&shader_resources_raw.subpass_inputs)?;
-            let storage_images = fill_resources(&shader_resources_raw.storage_images)?;
-            let sampled_images = fill_resources(&shader_resources_raw.sampled_images)?;
-            let atomic_counters = fill_resources(&shader_resources_raw.atomic_counters)?;
-            let acceleration_structures = fill_resources(&shader_resources_raw.acceleration_structures)?;
-            let push_constant_buffers =
-                fill_resources(&shader_resources_raw.push_constant_buffers)?;
-            let shader_record_buffers = fill_resources(&shader_resources_raw.shader_record_buffers)?;
-            let separate_images = fill_resources(&shader_resources_raw.separate_images)?;
-            let separate_samplers = fill_resources(&shader_resources_raw.separate_samplers)?;
-            let builtin_inputs = fill_builtin_resources(&shader_resources_raw.builtin_inputs)?;
-            let builtin_outputs = fill_builtin_resources(&shader_resources_raw.builtin_outputs)?;
-
-            Ok(spirv::ShaderResources {
-                uniform_buffers,
-                storage_buffers,
-                stage_inputs,
-                stage_outputs,
-                subpass_inputs,
-                storage_images,
-                sampled_images,
-                atomic_counters,
-                acceleration_structures,
-                push_constant_buffers,
-                shader_record_buffers,
-                separate_images,
-                separate_samplers,
-                builtin_inputs,
-                builtin_outputs,
-            })
+    pub fn get_variable_storage_class(&self, id: u32) -> Result<spirv::StorageClass, ErrorCode> {
+        let mut storage_class = spirv::StorageClass::Generic;
+        unsafe {
+            check!(br::sc_internal_compiler_get_variable_storage_class(
+                self.sc_compiler,
+                id,
+                &mut storage_class,
+            ));
         }
+        Ok(storage_class)
     }
 
     pub fn get_active_interface_variables(&self) -> Result<HashSet<u32>, ErrorCode> {
@@ -586,6 +980,169 @@ impl<TTargetData> Compiler<TTargetData> {
         }
     }
 
+    pub fn set_enabled_interface_variables(&mut self, ids: &HashSet<u32>) -> Result<(), ErrorCode> {
+        let id_list = ids.iter().cloned().collect::<Vec<_>>();
+        unsafe {
+            check!(br::sc_internal_compiler_set_enabled_interface_variables(
+                self.sc_compiler,
+                id_list.as_ptr(),
+                id_list.len(),
+            ));
+        }
+        self.enabled_interface_variables = Some(ids.clone());
+
+        Ok(())
+    }
+
+    pub fn get_entry_point_name(&self) -> Result<String, ErrorCode> {
+        let mut name_ptr = ptr::null();
+        unsafe {
+            check!(br::sc_internal_compiler_get_entry_point_name(
+                self.sc_compiler,
+                &mut name_ptr,
+            ));
+            let name = read_string_from_ptr(name_ptr)?;
+            check!(br::sc_internal_free_pointer(name_ptr as *mut c_void));
+            Ok(name)
+        }
+    }
+
+    pub fn set_entry_point(
+        &mut self,
+        name: &str,
+        execution_model: spirv::ExecutionModel,
+    ) -> Result<(), ErrorCode> {
+        let name = CString::new(name)?;
+        unsafe {
+            check!(br::sc_internal_compiler_set_entry_point(
+                self.sc_compiler,
+                name.as_ptr(),
+                execution_model,
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[cfg(any(feature = "glsl", feature = "hlsl", feature = "msl"))]
+    pub fn set_common_options(&self, options: &spirv::CommonOptions) -> Result<(), ErrorCode> {
+        let raw_options = br::ScCommonOptions {
+            flatten_multidimensional_arrays: options.flatten_multidimensional_arrays,
+            enable_row_major_load_workaround: options.enable_row_major_load_workaround,
+            ovr_multiview_view_count: options.ovr_multiview_view_count,
+        };
+        unsafe {
+            check!(br::sc_internal_compiler_set_common_options(
+                self.sc_compiler,
+                &raw_options,
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn get_execution_model(&self) -> Result<spirv::ExecutionModel, ErrorCode> {
+        let mut execution_model = spirv::ExecutionModel::Vertex;
+        unsafe {
+            check!(br::sc_internal_compiler_get_execution_model(
+                self.sc_compiler,
+                &mut execution_model,
+            ));
+        }
+        Ok(execution_model)
+    }
+
+    pub fn get_declared_capabilities(&self) -> Result<Vec<spirv::Capability>, ErrorCode> {
+        let mut capabilities = ptr::null_mut();
+        let mut size = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_get_declared_capabilities(
+                self.sc_compiler,
+                &mut capabilities,
+                &mut size,
+            ));
+            let result = read_into_vec_from_ptr(capabilities, size);
+            check!(br::sc_internal_free_pointer(capabilities as *mut c_void));
+            Ok(result)
+        }
+    }
+
+    pub fn get_addressing_model(&self) -> Result<br::spv::AddressingModel, ErrorCode> {
+        let mut addressing_model = br::spv::AddressingModel::Logical;
+        unsafe {
+            check!(br::sc_internal_compiler_get_addressing_model(
+                self.sc_compiler,
+                &mut addressing_model,
+            ));
+        }
+        Ok(addressing_model)
+    }
+
+    pub fn has_execution_mode(&self, mode: br::spv::ExecutionMode) -> Result<bool, ErrorCode> {
+        let mut result = false;
+        unsafe {
+            check!(br::sc_internal_compiler_has_execution_mode(
+                self.sc_compiler,
+                mode,
+                &mut result,
+            ));
+        }
+        Ok(result)
+    }
+
+    pub fn has_active_builtin(
+        &self,
+        builtin: br::spv::BuiltIn,
+        storage: br::spv::StorageClass,
+    ) -> Result<bool, ErrorCode> {
+        let mut result = false;
+        unsafe {
+            check!(br::sc_internal_compiler_has_active_builtin(
+                self.sc_compiler,
+                builtin,
+                storage,
+                &mut result,
+            ));
+        }
+        Ok(result)
+    }
+
+    pub fn get_execution_mode_argument(
+        &self,
+        mode: br::spv::ExecutionMode,
+        index: u32,
+    ) -> Result<u32, ErrorCode> {
+        let mut result = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_get_execution_mode_argument(
+                self.sc_compiler,
+                mode,
+                index,
+                &mut result,
+            ));
+        }
+        Ok(result)
+    }
+
+    pub fn set_interface_variable_name(
+        &self,
+        id: u32,
+        location: u32,
+        new_name: &str,
+    ) -> Result<(), ErrorCode> {
+        let new_name = CString::new(new_name)?;
+        unsafe {
+            check!(br::sc_internal_compiler_set_interface_variable_name(
+                self.sc_compiler,
+                id,
+                location,
+                new_name.as_ptr(),
+            ));
+        }
+
+        Ok(())
+    }
+
     pub fn rename_interface_variable(
         &self,
         resources: &[spirv::Resource],
@@ -597,11 +1154,11 @@ impl<TTargetData> Compiler<TTargetData> {
             for resource in resources.iter() {
                 match CString::new(&*resource.name) {
                     Ok(rn) => resources_names.push(rn),
-                    Err(_) => return Err(ErrorCode::Unhandled),
+                    Err(error) => return Err(error.into()),
                 }
             }
 
-            let new_name = CString::new(new_name).map_err(|_| ErrorCode::Unhandled)?;
+            let new_name = CString::new(new_name)?;
             let new_name_ptr = new_name.as_ptr();
             let resources = resources
                 .iter()
@@ -627,6 +1184,105 @@ impl<TTargetData> Compiler<TTargetData> {
         }
     }
 
+    #[cfg(feature = "debug-ir")]
+    pub fn dump_ir(&self) -> Result<String, ErrorCode> {
+        use std::fmt::Write;
+
+        let mut dump = String::new();
+        for id in 1..self.get_id_bound()? {
+            let mut kind_ptr = ptr::null();
+            let mut type_id = 0;
+            // The kind is a static string, so it isn't freed.
+            let kind = unsafe {
+                check!(br::sc_internal_compiler_debug_get_id(
+                    self.sc_compiler,
+                    id,
+                    &mut kind_ptr,
+                    &mut type_id,
+                ));
+                read_string_from_ptr(kind_ptr)?
+            };
+            if kind.is_empty() {
+                continue;
+            }
+
+            let _ = write!(dump, "%{} = {}", id, kind);
+            let name = self.get_name(id)?;
+            if !name.is_empty() {
+                let _ = write!(dump, " {:?}", name);
+            }
+            if type_id != 0 {
+                let _ = write!(dump, " : %{}", type_id);
+            }
+            if kind == "Variable" {
+                let _ = write!(dump, " {:?}", self.get_variable_storage_class(id)?);
+            }
+            dump.push('\n');
+
+            let mut member_count = 0;
+            if kind == "Type" {
+                let ty = self.get_type(id)?;
+                if let spirv::Type::Struct {
+                    ref member_types, ..
+                } = ty
+                {
+                    member_count = member_types.len() as u32;
+                }
+                let _ = writeln!(dump, "    {:?}", ty);
+            }
+
+            for decoration in self.debug_get_decorations(id, None)? {
+                let value = self.get_decoration(id, decoration)?;
+                let _ = writeln!(dump, "    {:?} = {}", decoration, value);
+            }
+
+            for index in 0..member_count {
+                let name = self.get_member_name(id, index)?;
+                let _ = writeln!(dump, "    member {} {:?}", index, name);
+                for decoration in self.debug_get_decorations(id, Some(index))? {
+                    let value = self.get_member_decoration(id, index, decoration)?;
+                    let _ = writeln!(dump, "        {:?} = {}", decoration, value);
+                }
+            }
+        }
+
+        Ok(dump)
+    }
+
+    #[cfg(feature = "debug-ir")]
+    fn debug_get_decorations(
+        &self,
+        id: u32,
+        member_index: Option<u32>,
+    ) -> Result<Vec<Decoration>, ErrorCode> {
+        let mut decorations_raw = ptr::null_mut();
+        let mut decorations_raw_length = 0;
+
+        unsafe {
+            match member_index {
+                Some(index) => check!(br::sc_internal_compiler_debug_get_member_decorations(
+                    self.sc_compiler,
+                    id,
+                    index,
+                    &mut decorations_raw,
+                    &mut decorations_raw_length,
+                )),
+                None => check!(br::sc_internal_compiler_debug_get_decorations(
+                    self.sc_compiler,
+                    id,
+                    &mut decorations_raw,
+                    &mut decorations_raw_length,
+                )),
+            }
+
+            let decorations = read_into_vec_from_ptr(decorations_raw, decorations_raw_length);
+
+            check!(br::sc_internal_free_pointer(decorations_raw as *mut c_void));
+
+            Ok(decorations)
+        }
+    }
+
     pub fn get_work_group_size_specialization_constants(
         &self,
     ) -> Result<spirv::WorkGroupSizeSpecializationConstants, ErrorCode> {
@@ -666,6 +1322,169 @@ impl<TTargetData> Compiler<TTargetData> {
     }
 }
 
+unsafe fn read_shader_resources(
+    shader_resources_raw: br::ScShaderResources,
+) -> Result<spirv::ShaderResources, ErrorCode> {
+    let fill_resources = |array_raw: &br::ScResourceArray| {
+        let resources = (0..array_raw.num)
+            .map(|i| {
+                let resource_raw = read_from_ptr::<br::ScResource>(array_raw.data.add(i));
+                let name = read_string_from_ptr(resource_raw.name)?;
+                check!(br::sc_internal_free_pointer(
+                    resource_raw.name as *mut c_void,
+                ));
+
+                Ok(spirv::Resource {
+                    id: resource_raw.id,
+                    type_id: resource_raw.type_id,
+                    base_type_id: resource_raw.base_type_id,
+                    name,
+                })
+            })
+            .collect::<Result<Vec<_>, ErrorCode>>();
+
+        check!(br::sc_internal_free_pointer(array_raw.data as *mut c_void));
+
+        resources
+    };
+
+    let fill_builtin_resources = |array_raw: &br::ScBuiltInResourceArray| {
+        let resources = (0..array_raw.num)
+            .map(|i| {
+                let resource_raw = read_from_ptr::<br::ScBuiltInResource>(array_raw.data.add(i));
+                let name = read_string_from_ptr(resource_raw.resource.name)?;
+                check!(br::sc_internal_free_pointer(
+                    resource_raw.resource.name as *mut c_void,
+                ));
+
+                Ok(spirv::BuiltInResource {
+                    builtin: spirv::BuiltIn::from_raw(resource_raw.builtin)?,
+                    value_type_id: resource_raw.value_type_id,
+                    resource: spirv::Resource {
+                        id: resource_raw.resource.id,
+                        type_id: resource_raw.resource.type_id,
+                        base_type_id: resource_raw.resource.base_type_id,
+                        name,
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>, ErrorCode>>();
+
+        check!(br::sc_internal_free_pointer(array_raw.data as *mut c_void));
+
+        resources
+    };
+
+    let uniform_buffers = fill_resources(&shader_resources_raw.uniform_buffers)?;
+    let storage_buffers = fill_resources(&shader_resources_raw.storage_buffers)?;
+    let stage_inputs = fill_resources(&shader_resources_raw.stage_inputs)?;
+    let stage_outputs = fill_resources(&shader_resources_raw.stage_outputs)?;
+    let subpass_inputs = fill_resources(&shader_resources_raw.subpass_inputs)?;
+    let storage_images = fill_resources(&shader_resources_raw.storage_images)?;
+    let sampled_images = fill_resources(&shader_resources_raw.sampled_images)?;
+    let atomic_counters = fill_resources(&shader_resources_raw.atomic_counters)?;
+    let acceleration_structures = fill_resources(&shader_resources_raw.acceleration_structures)?;
+    let push_constant_buffers =
+        fill_resources(&shader_resources_raw.push_constant_buffers)?;
+    let shader_record_buffers = fill_resources(&shader_resources_raw.shader_record_buffers)?;
+    let separate_images = fill_resources(&shader_resources_raw.separate_images)?;
+    let separate_samplers = fill_resources(&shader_resources_raw.separate_samplers)?;
+    let builtin_inputs = fill_builtin_resources(&shader_resources_raw.builtin_inputs)?;
+    let builtin_outputs = fill_builtin_resources(&shader_resources_raw.builtin_outputs)?;
+
+    Ok(spirv::ShaderResources {
+        uniform_buffers,
+        storage_buffers,
+        stage_inputs,
+        stage_outputs,
+        subpass_inputs,
+        storage_images,
+        sampled_images,
+        atomic_counters,
+        acceleration_structures,
+        push_constant_buffers,
+        shader_record_buffers,
+        separate_images,
+        separate_samplers,
+        builtin_inputs,
+        builtin_outputs,
+    })
+}
+
+/// Keywords which can precede an identifier in a statement without declaring it.
+#[cfg(any(feature = "glsl", feature = "hlsl", feature = "msl"))]
+const NON_TYPE_KEYWORDS: &[&str] = &["return", "else", "case", "goto", "do"];
+
+/// Finds the end of the first occurrence of the identifier `name` in `shader` that looks like
+/// a top-level variable declaration: outside of braces, preceded by a type and followed by the
+/// end of the declarator. Function parameters, which are not variables, are skipped.
+#[cfg(any(feature = "glsl", feature = "hlsl", feature = "msl"))]
+fn find_declaration(shader: &str, name: &str) -> Option<usize> {
+    let is_identifier = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut depth = 0i32;
+    let mut scanned = 0;
+    shader.match_indices(name).find_map(|(start, _)| {
+        for c in shader[scanned..start].chars() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+        scanned = start;
+        if depth != 0 {
+            return None;
+        }
+
+        let end = start + name.len();
+        let on_boundary = !shader[..start].ends_with(is_identifier)
+            && !shader[end..].starts_with(is_identifier);
+        let before = shader[..start].trim_end_matches(' ');
+        let follows_type = on_boundary
+            && before.len() != start
+            && match before.chars().last() {
+                // Pointer and reference declarators are attached to their type, whereas
+                // multiplication and bitwise and are surrounded by spaces.
+                Some('&') | Some('*') => before[..before.len() - 1].ends_with(is_identifier),
+                Some('}') | Some('>') => true,
+                Some(c) if is_identifier(c) => {
+                    let word_start = before
+                        .rfind(|c: char| !is_identifier(c))
+                        .map_or(0, |i| i + 1);
+                    !NON_TYPE_KEYWORDS.contains(&&before[word_start..])
+                }
+                _ => false,
+            };
+        let ends_declarator = [";", "[", " =", " :", " [["]
+            .iter()
+            .any(|suffix| shader[end..].starts_with(suffix));
+        if follows_type && ends_declarator {
+            Some(end)
+        } else {
+            None
+        }
+    })
+}
+
+/// Parses a module without constructing a compiler, returning whether it declares any entry
+/// points and its addressing model.
+pub fn parse_module(
+    module: &spirv::Module,
+) -> Result<(bool, br::spv::AddressingModel), ErrorCode> {
+    let mut has_entry_points = false;
+    let mut addressing_model = br::spv::AddressingModel::Logical;
+    unsafe {
+        check!(br::sc_internal_parse(
+            module.words.as_ptr(),
+            module.words.len(),
+            &mut has_entry_points,
+            &mut addressing_model,
+        ));
+    }
+
+    Ok((has_entry_points, addressing_model))
+}
+
 impl<TTargetData> Drop for Compiler<TTargetData> {
     fn drop(&mut self) {
         unsafe {
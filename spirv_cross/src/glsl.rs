@@ -1,8 +1,10 @@
 use crate::bindings as br;
-use crate::ptr_util::read_into_vec_from_ptr;
+use crate::ptr_util::{read_into_vec_from_ptr, read_string_from_ptr};
 use crate::{compiler, spirv, ErrorCode};
+use std::collections::HashSet;
 use std::ffi::CString;
 use std::marker::PhantomData;
+use std::os::raw::c_void;
 use std::ptr;
 
 /// A GLSL target.
@@ -11,10 +13,19 @@ pub enum Target {}
 
 pub struct TargetData {
     combined_image_samplers_built: bool,
+    keep_unnamed_blocks: bool,
 }
 
 impl spirv::Target for Target {
     type Data = TargetData;
+
+    fn reset_entry_point_data(data: &mut TargetData) {
+        data.combined_image_samplers_built = false;
+    }
+
+    fn is_reserved_name(name: &str) -> bool {
+        spirv::is_reserved_glsl_name(name)
+    }
 }
 
 #[allow(non_snake_case, non_camel_case_types)]
@@ -40,7 +51,32 @@ pub enum Version {
     V3_20Es,
 }
 
-#[derive(Debug, Clone)]
+impl Version {
+    fn as_raw(self) -> (u32, bool) {
+        use self::Version::*;
+        match self {
+            V1_10 => (1_10, false),
+            V1_20 => (1_20, false),
+            V1_30 => (1_30, false),
+            V1_40 => (1_40, false),
+            V1_50 => (1_50, false),
+            V3_30 => (3_30, false),
+            V4_00 => (4_00, false),
+            V4_10 => (4_10, false),
+            V4_20 => (4_20, false),
+            V4_30 => (4_30, false),
+            V4_40 => (4_40, false),
+            V4_50 => (4_50, false),
+            V4_60 => (4_60, false),
+            V1_00Es => (1_00, true),
+            V3_00Es => (3_00, true),
+            V3_10Es => (3_10, true),
+            V3_20Es => (3_20, true),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub struct CompilerVertexOptions {
     pub invert_y: bool,
     pub transform_clip_space: bool,
@@ -67,7 +103,7 @@ pub enum Precision {
     High = 3,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub struct CompilerFragmentOptions {
     pub default_float_precision: Precision,
     pub default_int_precision: Precision,
@@ -84,22 +120,43 @@ impl Default for CompilerFragmentOptions {
 
 /// GLSL compiler options.
 #[non_exhaustive]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub struct CompilerOptions {
     pub version: Version,
     pub force_temporary: bool,
     pub vulkan_semantics: bool,
     pub separate_shader_objects: bool,
+    /// Whether to flatten arrays of arrays. Also enabled by
+    /// `common.flatten_multidimensional_arrays`.
     pub flatten_multidimensional_arrays: bool,
     pub enable_420_pack_extension: bool,
     pub emit_push_constant_as_uniform_buffer: bool,
     pub emit_uniform_buffer_as_plain_uniforms: bool,
+    /// Whether to emit `#line` directives mapping back to the original source, using the
+    /// `OpLine` debug info in the module.
     pub emit_line_directives: bool,
     pub enable_storage_image_qualifier_deduction: bool,
     /// Whether to force all uninitialized variables to be initialized to zero.
     pub force_zero_initialized_variables: bool,
+    /// Whether to flatten input and output interface blocks into individual variables,
+    /// for targets such as WebGL 2 which do not support them.
+    pub force_flattened_io_blocks: bool,
+    /// Whether buffer block instances without a debug name are left unnamed, in which case
+    /// SPIRV-Cross synthesizes an instance name from the id, such as `_22`, and members are
+    /// referenced as `_22.member`. Otherwise, they are named after their block type with an
+    /// `_instance` suffix while compiling, giving names that are stable across recompilations
+    /// of the source shader. The names are only used for the compiled output, and are not
+    /// reported by reflection such as `Ast::get_name`.
+    pub keep_unnamed_blocks: bool,
     pub vertex: CompilerVertexOptions,
+    /// Clip space conventions overriding `vertex.invert_y` and `vertex.transform_clip_space`.
+    pub clip_space: Option<spirv::ClipSpaceOptions>,
     pub fragment: CompilerFragmentOptions,
+    /// Options shared with the other targets.
+    pub common: spirv::CommonOptions,
+    /// Whether to append an `/* id=N */` comment to variable declarations, giving the SPIR-V
+    /// id each variable was emitted from. Intended for debugging generated shaders.
+    pub annotate_ids: bool,
     /// The name and execution model of the entry point to use. If no entry
     /// point is specified, then the first entry point found will be used.
     pub entry_point: Option<(String, spirv::ExecutionModel)>,
@@ -119,15 +176,67 @@ impl Default for CompilerOptions {
             emit_line_directives: false,
             enable_storage_image_qualifier_deduction: true,
             force_zero_initialized_variables: false,
+            force_flattened_io_blocks: false,
+            keep_unnamed_blocks: true,
             vertex: CompilerVertexOptions::default(),
+            clip_space: None,
             fragment: CompilerFragmentOptions::default(),
+            common: spirv::CommonOptions::default(),
+            annotate_ids: false,
             entry_point: None,
         }
     }
 }
 
+impl CompilerOptions {
+    /// Options for GLSL ES 3.00, as accepted by WebGL 2 contexts.
+    ///
+    /// Compared to the defaults, this preset:
+    /// - targets `#version 300 es`, without `GL_ARB_shading_language_420pack`,
+    /// - flattens input and output interface blocks into individual variables,
+    /// - flattens arrays of arrays, which GLSL ES 3.00 does not support,
+    /// - does not rely on `gl_BaseInstance`, which WebGL does not expose.
+    ///
+    /// The preset only sets compiler options and does not transform the shader. Compiling
+    /// for GLSL ES 3.00 fails for shaders indexing arrays of samplers with non-constant
+    /// expressions, which GLSL ES does not support.
+    pub fn webgl2() -> CompilerOptions {
+        CompilerOptions {
+            version: Version::V3_00Es,
+            enable_420_pack_extension: false,
+            flatten_multidimensional_arrays: true,
+            force_flattened_io_blocks: true,
+            vertex: CompilerVertexOptions {
+                support_nonzero_base_instance: false,
+                ..CompilerVertexOptions::default()
+            },
+            ..CompilerOptions::default()
+        }
+    }
+
+    /// Options for GLSL ES 1.00, as accepted by WebGL 1 contexts.
+    ///
+    /// In addition to the constraints of [`CompilerOptions::webgl2`], this preset targets
+    /// `#version 100` and emits uniform buffers as plain uniforms, since GLSL ES 1.00 has no
+    /// uniform blocks.
+    ///
+    /// Like [`CompilerOptions::webgl2`], the preset only sets compiler options. Compiling for
+    /// GLSL ES 1.00 also fails for shaders with arrays of uniform buffers, which have no plain
+    /// uniform equivalent. Individual uniform buffers can be flattened into a single `vec4`
+    /// array with `Ast::flatten_buffer_block` instead of being emitted as plain uniforms.
+    pub fn webgl1() -> CompilerOptions {
+        CompilerOptions {
+            version: Version::V1_00Es,
+            emit_uniform_buffer_as_plain_uniforms: true,
+            ..CompilerOptions::webgl2()
+        }
+    }
+}
+
 impl spirv::Parse<Target> for spirv::Ast<Target> {
     fn parse(module: &spirv::Module) -> Result<Self, ErrorCode> {
+        module.validate_header()?;
+
         let compiler = {
             let mut compiler = ptr::null_mut();
             unsafe {
@@ -142,8 +251,13 @@ impl spirv::Parse<Target> for spirv::Ast<Target> {
                 sc_compiler: compiler,
                 target_data: TargetData {
                     combined_image_samplers_built: false,
+                    keep_unnamed_blocks: true,
                 },
                 has_been_compiled: false,
+                annotate_ids: false,
+                type_cache: Default::default(),
+                compile_cache: Default::default(),
+                enabled_interface_variables: None,
             }
         };
 
@@ -160,42 +274,22 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
     /// Set GLSL compiler specific compilation settings.
     fn set_compiler_options(&mut self, options: &CompilerOptions) -> Result<(), ErrorCode> {
         if let Some((name, model)) = &options.entry_point {
-            let name_raw = CString::new(name.as_str()).map_err(|_| ErrorCode::Unhandled)?;
-            let model = *model;
-            unsafe {
-                check!(br::sc_internal_compiler_set_entry_point(
-                    self.compiler.sc_compiler,
-                    name_raw.as_ptr(),
-                    model,
-                ));
-            }
-
-            self.compiler.target_data.combined_image_samplers_built = false;
+            self.select_entry_point(name, *model)?;
         };
 
-        use self::Version::*;
-        let (version, es) = match options.version {
-            V1_10 => (1_10, false),
-            V1_20 => (1_20, false),
-            V1_30 => (1_30, false),
-            V1_40 => (1_40, false),
-            V1_50 => (1_50, false),
-            V3_30 => (3_30, false),
-            V4_00 => (4_00, false),
-            V4_10 => (4_10, false),
-            V4_20 => (4_20, false),
-            V4_30 => (4_30, false),
-            V4_40 => (4_40, false),
-            V4_50 => (4_50, false),
-            V4_60 => (4_60, false),
-            V1_00Es => (1_00, true),
-            V3_00Es => (3_00, true),
-            V3_10Es => (3_10, true),
-            V3_20Es => (3_20, true),
+        let (vertex_invert_y, vertex_transform_clip_space) = match options.clip_space {
+            Some(clip_space) => (clip_space.flip_y, clip_space.zero_to_one_depth),
+            None => (options.vertex.invert_y, options.vertex.transform_clip_space),
+        };
+        let common = spirv::CommonOptions {
+            flatten_multidimensional_arrays: options.flatten_multidimensional_arrays
+                || options.common.flatten_multidimensional_arrays,
+            ..options.common
         };
+        let (version, es) = options.version.as_raw();
         let raw_options = br::ScGlslCompilerOptions {
-            vertex_invert_y: options.vertex.invert_y,
-            vertex_transform_clip_space: options.vertex.transform_clip_space,
+            vertex_invert_y,
+            vertex_transform_clip_space,
             version,
             es,
             vertex_support_nonzero_base_instance: options.vertex.support_nonzero_base_instance,
@@ -204,7 +298,7 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
             force_temporary: options.force_temporary,
             vulkan_semantics: options.vulkan_semantics,
             separate_shader_objects: options.separate_shader_objects,
-            flatten_multidimensional_arrays: options.flatten_multidimensional_arrays,
+            flatten_multidimensional_arrays: common.flatten_multidimensional_arrays,
             enable_420_pack_extension: options.enable_420_pack_extension,
             emit_push_constant_as_uniform_buffer: options.emit_push_constant_as_uniform_buffer,
             emit_uniform_buffer_as_plain_uniforms: options.emit_uniform_buffer_as_plain_uniforms,
@@ -212,6 +306,7 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
             enable_storage_image_qualifier_deduction: options
                 .enable_storage_image_qualifier_deduction,
             force_zero_initialized_variables: options.force_zero_initialized_variables,
+            force_flattened_io_blocks: options.force_flattened_io_blocks,
         };
         unsafe {
             check!(br::sc_internal_compiler_glsl_set_options(
@@ -220,17 +315,149 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
             ));
         }
 
+        self.compiler.set_common_options(&common)?;
+
+        self.compiler.annotate_ids = options.annotate_ids;
+
+        self.compiler.target_data.keep_unnamed_blocks = options.keep_unnamed_blocks;
+
         Ok(())
     }
 
     /// Generate GLSL shader from the AST.
     fn compile(&mut self) -> Result<String, ErrorCode> {
         self.build_combined_image_samplers()?;
-        self.compiler.compile()
+        self.check_webgl_limits()?;
+        if self.compiler.target_data.keep_unnamed_blocks {
+            return self.compiler.compile();
+        }
+
+        let named_blocks = self.name_unnamed_blocks()?;
+        let shader = self.compiler.compile();
+        for id in named_blocks {
+            self.compiler.set_name(id, "")?;
+        }
+
+        shader
     }
 }
 
+/// Finds an array of images, samplers or sampled images that is indexed with an expression
+/// other than a constant, by scanning the instructions of `words`, and returns the id of the
+/// variable holding it.
+fn find_dynamically_indexed_sampler_array(words: &[u32]) -> Option<u32> {
+    const OP_TYPE_IMAGE: u32 = 25;
+    const OP_TYPE_SAMPLER: u32 = 26;
+    const OP_TYPE_SAMPLED_IMAGE: u32 = 27;
+    const OP_TYPE_ARRAY: u32 = 28;
+    const OP_TYPE_RUNTIME_ARRAY: u32 = 29;
+    const OP_TYPE_POINTER: u32 = 32;
+    const OP_CONSTANT: u32 = 43;
+    const OP_SPEC_CONSTANT: u32 = 50;
+    const OP_VARIABLE: u32 = 59;
+    const OP_ACCESS_CHAIN: u32 = 65;
+    const OP_IN_BOUNDS_ACCESS_CHAIN: u32 = 66;
+
+    let mut offset = spirv::HEADER_WORD_COUNT;
+    let instructions = std::iter::from_fn(|| {
+        let word_count = (*words.get(offset)? >> 16) as usize;
+        let instruction = words.get(offset..offset + word_count.max(1))?;
+        offset += word_count.max(1);
+        Some((instruction[0] & 0xffff, &instruction[1..]))
+    });
+
+    let mut opaque_types = HashSet::new();
+    let mut opaque_array_types = HashSet::new();
+    let mut opaque_array_pointers = HashSet::new();
+    let mut opaque_array_variables = HashSet::new();
+    let mut constants = HashSet::new();
+    for (opcode, operands) in instructions {
+        match (opcode, operands) {
+            (OP_TYPE_IMAGE, &[id, ..])
+            | (OP_TYPE_SAMPLER, &[id])
+            | (OP_TYPE_SAMPLED_IMAGE, &[id, _]) => {
+                opaque_types.insert(id);
+            }
+            (OP_TYPE_ARRAY, &[id, element, _]) | (OP_TYPE_RUNTIME_ARRAY, &[id, element])
+                if opaque_types.contains(&element) || opaque_array_types.contains(&element) =>
+            {
+                opaque_array_types.insert(id);
+            }
+            (OP_TYPE_POINTER, &[id, _, pointee]) if opaque_array_types.contains(&pointee) => {
+                opaque_array_pointers.insert(id);
+            }
+            (OP_CONSTANT, &[_, id, ..]) | (OP_SPEC_CONSTANT, &[_, id, ..]) => {
+                constants.insert(id);
+            }
+            (OP_VARIABLE, &[ty, id, ..]) if opaque_array_pointers.contains(&ty) => {
+                opaque_array_variables.insert(id);
+            }
+            (OP_ACCESS_CHAIN, &[_, _, base, ref indices @ ..])
+            | (OP_IN_BOUNDS_ACCESS_CHAIN, &[_, _, base, ref indices @ ..])
+                if opaque_array_variables.contains(&base)
+                    && indices.iter().any(|index| !constants.contains(index)) =>
+            {
+                return Some(base);
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
 impl spirv::Ast<Target> {
+    /// Fails for shaders which GLSL ES 1.00 and 3.00, as targeted by the WebGL presets, cannot
+    /// express and which SPIRV-Cross emits as is: arrays of uniform buffers in GLSL ES 1.00, and
+    /// arrays of samplers indexed with non-constant expressions in either.
+    fn check_webgl_limits(&self) -> Result<(), ErrorCode> {
+        let mut version = 0;
+        let mut es = false;
+        unsafe {
+            check!(br::sc_internal_compiler_glsl_get_version(
+                self.compiler.sc_compiler,
+                &mut version,
+                &mut es,
+            ));
+        }
+        if !es || version > 300 {
+            return Ok(());
+        }
+
+        if version == 100 {
+            for buffer in self.compiler.get_shader_resources()?.uniform_buffers {
+                if let spirv::Type::Struct { array, .. } = self.compiler.get_type(buffer.type_id)? {
+                    if !array.is_empty() {
+                        return Err(ErrorCode::CompilationError(format!(
+                            "uniform buffer array `{}` is not supported by GLSL ES 1.00",
+                            buffer.name
+                        )));
+                    }
+                }
+            }
+        }
+
+        let words = unsafe {
+            let mut words_ptr = ptr::null();
+            let mut words_len = 0;
+            check!(br::sc_internal_compiler_glsl_get_spirv(
+                self.compiler.sc_compiler,
+                &mut words_ptr,
+                &mut words_len,
+            ));
+            read_into_vec_from_ptr(words_ptr, words_len)
+        };
+        match find_dynamically_indexed_sampler_array(&words) {
+            Some(id) => Err(ErrorCode::CompilationError(format!(
+                "sampler array `{}` is indexed with a non-constant expression, which GLSL ES {} \
+                 does not support",
+                self.compiler.get_name(id)?,
+                if version == 100 { "1.00" } else { "3.00" }
+            ))),
+            None => Ok(()),
+        }
+    }
+
     pub fn build_combined_image_samplers(&mut self) -> Result<(), ErrorCode> {
         unsafe {
             if !self.compiler.target_data.combined_image_samplers_built {
@@ -248,7 +475,7 @@ impl spirv::Ast<Target> {
         &mut self,
     ) -> Result<Vec<spirv::CombinedImageSampler>, ErrorCode> {
         self.build_combined_image_samplers()?;
-        unsafe {
+        let samplers_raw = unsafe {
             let mut samplers_raw: *const br::ScCombinedImageSampler = std::ptr::null();
             let mut samplers_raw_length: usize = 0;
 
@@ -258,20 +485,61 @@ impl spirv::Ast<Target> {
                 &mut samplers_raw_length as _,
             ));
 
-            let samplers = read_into_vec_from_ptr(samplers_raw, samplers_raw_length)
-                .iter()
-                .map(|sc| spirv::CombinedImageSampler {
+            read_into_vec_from_ptr(samplers_raw, samplers_raw_length)
+        };
+
+        samplers_raw
+            .iter()
+            .map(|sc| {
+                let image_type_id = self.compiler.get_variable_type_id(sc.image_id)?;
+                let comparison = match self.compiler.get_type(image_type_id)? {
+                    spirv::Type::Image { image, .. } => image.depth,
+                    _ => false,
+                };
+                Ok(spirv::CombinedImageSampler {
                     combined_id: sc.combined_id,
                     image_id: sc.image_id,
                     sampler_id: sc.sampler_id,
+                    comparison,
                 })
-                .collect();
+            })
+            .collect()
+    }
 
-            Ok(samplers)
+    /// Compiles to `version`, overriding `CompilerOptions::version` for this call only. The
+    /// previously set version is restored afterwards, even if compilation fails.
+    pub fn compile_version(&mut self, version: Version) -> Result<String, ErrorCode> {
+        let mut previous_version = 0;
+        let mut previous_es = false;
+        unsafe {
+            check!(br::sc_internal_compiler_glsl_get_version(
+                self.compiler.sc_compiler,
+                &mut previous_version,
+                &mut previous_es,
+            ));
+            let (version, es) = version.as_raw();
+            check!(br::sc_internal_compiler_glsl_set_version(
+                self.compiler.sc_compiler,
+                version,
+                es,
+            ));
+        }
+
+        let shader = self.compile();
+
+        unsafe {
+            check!(br::sc_internal_compiler_glsl_set_version(
+                self.compiler.sc_compiler,
+                previous_version,
+                previous_es,
+            ));
         }
+
+        shader
     }
 
     pub fn add_header_line(&mut self, line: &str) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
         unsafe {
             let line = CString::new(line);
             match line {
@@ -281,14 +549,61 @@ impl spirv::Ast<Target> {
                         line.as_ptr(),
                     ));
                 }
-                _ => return Err(ErrorCode::Unhandled),
+                Err(error) => return Err(error.into()),
             }
 
             Ok(())
         }
     }
 
+    /// Sets a member name that is emitted unchanged. SPIRV-Cross replaces member names that
+    /// are reserved, such as GLSL keywords, built-in type and function names, names beginning
+    /// with `gl_` or `spv`, names containing `__` or of the form `_m0`, and deduplicates names
+    /// within a struct, so this fails for such names instead of letting the emitted GLSL
+    /// diverge from the name known to the host.
+    pub fn force_member_name(&mut self, id: u32, index: u32, name: &str) -> Result<(), ErrorCode> {
+        let is_reserved_member_name = name.starts_with("spv")
+            || name.contains("__")
+            || (name.len() > 2
+                && name.starts_with("_m")
+                && name[2..].chars().all(|c| c.is_ascii_digit()));
+        if is_reserved_member_name {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "`{}` is a reserved member name",
+                name
+            )));
+        }
+
+        self.set_member_name_checked(id, index, name)
+    }
+
+    /// Names each buffer block instance without a debug name after its block type, returning
+    /// the ids of the instances named.
+    fn name_unnamed_blocks(&mut self) -> Result<Vec<u32>, ErrorCode> {
+        let shader_resources = self.compiler.get_shader_resources()?;
+        let blocks = shader_resources
+            .uniform_buffers
+            .iter()
+            .chain(&shader_resources.storage_buffers)
+            .chain(&shader_resources.push_constant_buffers);
+        let mut named_blocks = Vec::new();
+        for block in blocks {
+            if !self.compiler.get_name(block.id)?.is_empty() {
+                continue;
+            }
+            let block_name = self.compiler.get_name(block.base_type_id)?;
+            if !block_name.is_empty() {
+                self.compiler
+                    .set_name(block.id, &format!("{}_instance", block_name))?;
+                named_blocks.push(block.id);
+            }
+        }
+
+        Ok(named_blocks)
+    }
+
     pub fn flatten_buffer_block(&mut self, id: u32) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
         unsafe {
             check!(br::sc_internal_compiler_glsl_flatten_buffer_block(
                 self.compiler.sc_compiler,
@@ -298,4 +613,39 @@ impl spirv::Ast<Target> {
             Ok(())
         }
     }
+
+    /// Gets the GLSL extensions the compiled shader declares with `#extension`, including
+    /// those SPIRV-Cross added automatically. These are not the SPIR-V extensions declared by
+    /// the module. `compile` must be called first.
+    pub fn get_required_extensions(&self) -> Result<Vec<String>, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        let mut extensions_raw = ptr::null_mut();
+        let mut extensions_raw_length = 0;
+
+        unsafe {
+            check!(br::sc_internal_compiler_glsl_get_required_extensions(
+                self.compiler.sc_compiler,
+                &mut extensions_raw,
+                &mut extensions_raw_length,
+            ));
+
+            let extensions = read_into_vec_from_ptr(extensions_raw, extensions_raw_length)
+                .iter()
+                .map(|&extension_raw| {
+                    let extension = read_string_from_ptr(extension_raw)?;
+                    check!(br::sc_internal_free_pointer(extension_raw as *mut c_void));
+                    Ok(extension)
+                })
+                .collect::<Result<Vec<_>, _>>();
+
+            check!(br::sc_internal_free_pointer(extensions_raw as *mut c_void));
+
+            extensions
+        }
+    }
 }
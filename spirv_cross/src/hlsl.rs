@@ -1,17 +1,66 @@
 use crate::bindings as br;
 use crate::{compiler, spirv, ErrorCode};
-use std::ffi::CString;
 use std::marker::PhantomData;
 use std::ptr;
 
 pub use crate::bindings::root::ScHlslRootConstant as RootConstant;
 
+/// HLSL keywords and object types that SPIRV-Cross renames when they are used as
+/// identifiers, excluding the scalar, vector and matrix types matched by
+/// `is_reserved_hlsl_name`.
+#[rustfmt::skip]
+const RESERVED_NAMES: &[&str] = &[
+    "AppendStructuredBuffer", "asm", "asm_fragment", "BlendState", "bool", "break", "Buffer",
+    "ByteAddressBuffer", "case", "cbuffer", "centroid", "class", "column_major", "compile",
+    "compile_fragment", "CompileShader", "const", "continue", "ComputeShader",
+    "ConsumeStructuredBuffer", "default", "DepthStencilState", "DepthStencilView", "discard",
+    "do", "double", "DomainShader", "dword", "else", "export", "false", "float", "for",
+    "fxgroup", "GeometryShader", "groupshared", "half", "HullShader", "indices", "if", "in",
+    "inline", "inout", "InputPatch", "int", "interface", "line", "lineadj", "linear",
+    "LineStream", "matrix", "min16float", "min10float", "min16int", "min16uint", "namespace",
+    "nointerpolation", "noperspective", "NULL", "out", "OutputPatch", "payload", "packoffset",
+    "pass", "pixelfragment", "PixelShader", "point", "PointStream", "precise",
+    "RasterizerState", "RenderTargetView", "return", "register", "row_major", "RWBuffer",
+    "RWByteAddressBuffer", "RWStructuredBuffer", "RWTexture1D", "RWTexture1DArray",
+    "RWTexture2D", "RWTexture2DArray", "RWTexture3D", "sample", "sampler", "SamplerState",
+    "SamplerComparisonState", "shared", "snorm", "stateblock", "stateblock_state", "static",
+    "string", "struct", "switch", "StructuredBuffer", "tbuffer", "technique", "technique10",
+    "technique11", "texture", "Texture1D", "Texture1DArray", "Texture2D", "Texture2DArray",
+    "Texture2DMS", "Texture2DMSArray", "Texture3D", "TextureCube", "TextureCubeArray", "true",
+    "typedef", "triangle", "triangleadj", "TriangleStream", "uint", "uniform", "unorm",
+    "unsigned", "vector", "vertexfragment", "VertexShader", "vertices", "void", "volatile",
+    "while",
+];
+
+/// Returns whether `name` is reserved by HLSL or, as SPIRV-Cross also applies those, GLSL.
+fn is_reserved_hlsl_name(name: &str) -> bool {
+    if RESERVED_NAMES.contains(&name) || spirv::is_reserved_glsl_name(name) {
+        return true;
+    }
+
+    let is_dimension = |c: &u8| (b'1'..=b'4').contains(c);
+    [
+        "bool", "int", "uint", "dword", "half", "float", "double", "min16float", "min10float",
+        "min16int", "min12int", "min16uint",
+    ]
+    .iter()
+    .any(|scalar| match name.strip_prefix(scalar).map(str::as_bytes) {
+        Some([rows]) => is_dimension(rows),
+        Some([rows, b'x', columns]) => is_dimension(rows) && is_dimension(columns),
+        _ => false,
+    })
+}
+
 /// A HLSL target.
 #[derive(Debug, Clone)]
 pub enum Target {}
 
 impl spirv::Target for Target {
     type Data = ();
+
+    fn is_reserved_name(name: &str) -> bool {
+        is_reserved_hlsl_name(name)
+    }
 }
 
 /// A HLSL shader model version.
@@ -48,7 +97,7 @@ impl ShaderModel {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq)]
 pub struct CompilerVertexOptions {
     pub invert_y: bool,
     pub transform_clip_space: bool,
@@ -56,7 +105,7 @@ pub struct CompilerVertexOptions {
 
 /// HLSL compiler options.
 #[non_exhaustive]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub struct CompilerOptions {
     pub shader_model: ShaderModel,
     /// Support point size builtin but ignore the value.
@@ -64,10 +113,23 @@ pub struct CompilerOptions {
     /// Support point coordinate builtin but ignore the value.
     pub point_coord_compat: bool,
     pub vertex: CompilerVertexOptions,
+    /// Clip space conventions overriding `vertex.invert_y` and `vertex.transform_clip_space`.
+    pub clip_space: Option<spirv::ClipSpaceOptions>,
     pub force_storage_buffer_as_uav: bool,
     pub nonwritable_uav_texture_as_srv: bool,
     /// Whether to force all uninitialized variables to be initialized to zero.
     pub force_zero_initialized_variables: bool,
+    /// Whether to flatten matrix vertex inputs into one semantic per column,
+    /// e.g. `TEXCOORD0`, `TEXCOORD1`, instead of a single `TEXCOORD0`.
+    pub flatten_matrix_vertex_input_semantics: bool,
+    /// Whether to emit storage buffers wrapping a single runtime array as
+    /// `StructuredBuffer` rather than `ByteAddressBuffer`.
+    pub preserve_structured_buffers: bool,
+    /// Options shared with the other targets.
+    pub common: spirv::CommonOptions,
+    /// Whether to append an `/* id=N */` comment to variable declarations, giving the SPIR-V
+    /// id each variable was emitted from. Intended for debugging generated shaders.
+    pub annotate_ids: bool,
     /// The name and execution model of the entry point to use. If no entry
     /// point is specified, then the first entry point found will be used.
     pub entry_point: Option<(String, spirv::ExecutionModel)>,
@@ -80,9 +142,14 @@ impl Default for CompilerOptions {
             point_size_compat: false,
             point_coord_compat: false,
             vertex: CompilerVertexOptions::default(),
+            clip_space: None,
             force_storage_buffer_as_uav: false,
             nonwritable_uav_texture_as_srv: false,
             force_zero_initialized_variables: false,
+            flatten_matrix_vertex_input_semantics: false,
+            preserve_structured_buffers: false,
+            common: spirv::CommonOptions::default(),
+            annotate_ids: false,
             entry_point: None,
         }
     }
@@ -90,6 +157,8 @@ impl Default for CompilerOptions {
 
 impl spirv::Parse<Target> for spirv::Ast<Target> {
     fn parse(module: &spirv::Module) -> Result<Self, ErrorCode> {
+        module.validate_header()?;
+
         let compiler = {
             let mut compiler = ptr::null_mut();
             unsafe {
@@ -104,6 +173,10 @@ impl spirv::Parse<Target> for spirv::Ast<Target> {
                 sc_compiler: compiler,
                 target_data: (),
                 has_been_compiled: false,
+                annotate_ids: false,
+                type_cache: Default::default(),
+                compile_cache: Default::default(),
+                enabled_interface_variables: None,
             }
         };
 
@@ -120,25 +193,23 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
     /// Set HLSL compiler specific compilation settings.
     fn set_compiler_options(&mut self, options: &CompilerOptions) -> Result<(), ErrorCode> {
         if let Some((name, model)) = &options.entry_point {
-            let name_raw = CString::new(name.as_str()).map_err(|_| ErrorCode::Unhandled)?;
-            let model = *model;
-            unsafe {
-                check!(br::sc_internal_compiler_set_entry_point(
-                    self.compiler.sc_compiler,
-                    name_raw.as_ptr(),
-                    model,
-                ));
-            }
+            self.select_entry_point(name, *model)?;
+        };
+        let (vertex_invert_y, vertex_transform_clip_space) = match options.clip_space {
+            Some(clip_space) => (clip_space.flip_y, !clip_space.zero_to_one_depth),
+            None => (options.vertex.invert_y, options.vertex.transform_clip_space),
         };
         let raw_options = br::ScHlslCompilerOptions {
             shader_model: options.shader_model.as_raw(),
             point_size_compat: options.point_size_compat,
             point_coord_compat: options.point_coord_compat,
-            vertex_invert_y: options.vertex.invert_y,
-            vertex_transform_clip_space: options.vertex.transform_clip_space,
+            vertex_invert_y,
+            vertex_transform_clip_space,
             force_storage_buffer_as_uav: options.force_storage_buffer_as_uav,
             nonwritable_uav_texture_as_srv: options.nonwritable_uav_texture_as_srv,
             force_zero_initialized_variables: options.force_zero_initialized_variables,
+            flatten_matrix_vertex_input_semantics: options.flatten_matrix_vertex_input_semantics,
+            preserve_structured_buffers: options.preserve_structured_buffers,
         };
         unsafe {
             check!(br::sc_internal_compiler_hlsl_set_options(
@@ -147,6 +218,10 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
             ));
         }
 
+        self.compiler.set_common_options(&options.common)?;
+
+        self.compiler.annotate_ids = options.annotate_ids;
+
         Ok(())
     }
 
@@ -159,6 +234,7 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
 impl spirv::Ast<Target> {
     ///
     pub fn set_root_constant_layout(&mut self, layout: Vec<RootConstant>) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
         unsafe {
             check!(br::sc_internal_compiler_hlsl_set_root_constant_layout(
                 self.compiler.sc_compiler,
@@ -169,4 +245,39 @@ impl spirv::Ast<Target> {
 
         Ok(())
     }
+
+    /// Compiles for `shader_model`, overriding `CompilerOptions::shader_model` for this call
+    /// only. The previously set shader model is restored afterwards, even if compilation fails.
+    pub fn compile_version(&mut self, shader_model: ShaderModel) -> Result<String, ErrorCode> {
+        let mut previous_shader_model = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_hlsl_get_shader_model(
+                self.compiler.sc_compiler,
+                &mut previous_shader_model,
+            ));
+            check!(br::sc_internal_compiler_hlsl_set_shader_model(
+                self.compiler.sc_compiler,
+                shader_model.as_raw(),
+            ));
+        }
+
+        let shader = self.compile();
+
+        unsafe {
+            check!(br::sc_internal_compiler_hlsl_set_shader_model(
+                self.compiler.sc_compiler,
+                previous_shader_model,
+            ));
+        }
+
+        shader
+    }
+
+    /// Gets the name of the struct the current entry point returns its stage outputs in.
+    /// `compile` must be called first.
+    pub fn get_stage_output_struct_name(&self) -> Result<String, ErrorCode> {
+        self.check_stage_output_struct()?;
+
+        Ok(String::from("SPIRV_Cross_Output"))
+    }
 }
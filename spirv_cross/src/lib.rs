@@ -1,46 +1,18 @@
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "backend", target_arch = "wasm32"))]
 macro_rules! check {
     ($check:expr) => {{
         $check
     }};
 }
 
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(feature = "backend", not(target_arch = "wasm32")))]
 macro_rules! check {
     ($check:expr) => {{
-        let result = $check;
-        if br::ScInternalResult::Success != result {
-            if br::ScInternalResult::CompilationError == result {
-                let mut message_ptr = ptr::null();
-
-                if br::ScInternalResult::Success
-                    != br::sc_internal_get_latest_exception_message(&mut message_ptr)
-                {
-                    return Err(ErrorCode::Unhandled);
-                }
-
-                let message = match std::ffi::CStr::from_ptr(message_ptr)
-                    .to_owned()
-                    .into_string()
-                {
-                    Err(_) => return Err(ErrorCode::Unhandled),
-                    Ok(v) => v,
-                };
-
-                if br::ScInternalResult::Success
-                    != br::sc_internal_free_pointer(message_ptr as *mut std::os::raw::c_void)
-                {
-                    return Err(ErrorCode::Unhandled);
-                }
-
-                return Err(ErrorCode::CompilationError(message));
-            }
-
-            return Err(ErrorCode::Unhandled);
-        }
+        crate::ErrorCode::from_raw($check)?;
     }};
 }
 
+#[cfg(feature = "backend")]
 mod compiler;
 
 #[cfg(feature = "glsl")]
@@ -52,11 +24,12 @@ pub mod msl;
 
 pub mod spirv;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "backend", target_arch = "wasm32"))]
 pub(crate) mod emscripten;
+#[cfg(feature = "backend")]
 pub(crate) mod ptr_util;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(feature = "backend", target_arch = "wasm32"))]
 mod bindings_wasm_functions;
 
 #[cfg(target_arch = "wasm32")]
@@ -66,6 +39,7 @@ mod bindings {
     #![allow(non_camel_case_types)]
     #![allow(non_snake_case)]
     include!(concat!("bindings_wasm.rs"));
+    #[cfg(feature = "backend")]
     pub use crate::bindings_wasm_functions::*;
     pub use root::*;
 }
@@ -80,10 +54,35 @@ mod bindings {
     pub use root::*;
 }
 
-#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum ErrorCode {
     Unhandled,
     CompilationError(String),
+    /// An argument was rejected before being passed to SPIRV-Cross.
+    InvalidArgument(String),
+    /// A string passed to SPIRV-Cross contained an interior nul byte.
+    Nul(std::ffi::NulError),
+    /// A string returned by SPIRV-Cross was not valid UTF-8.
+    Utf8(std::str::Utf8Error),
+}
+
+// `NulError` and `Utf8Error` do not implement `Hash`, so hash the parts compared by `Eq`.
+impl std::hash::Hash for ErrorCode {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            ErrorCode::Unhandled => {}
+            ErrorCode::CompilationError(message) | ErrorCode::InvalidArgument(message) => {
+                message.hash(state)
+            }
+            ErrorCode::Nul(error) => error.nul_position().hash(state),
+            ErrorCode::Utf8(error) => {
+                error.valid_up_to().hash(state);
+                error.error_len().hash(state);
+            }
+        }
+    }
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -92,4 +91,64 @@ impl std::fmt::Display for ErrorCode {
     }
 }
 
-impl std::error::Error for ErrorCode {}
+impl std::error::Error for ErrorCode {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ErrorCode::Nul(error) => Some(error),
+            ErrorCode::Utf8(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::ffi::NulError> for ErrorCode {
+    fn from(error: std::ffi::NulError) -> Self {
+        ErrorCode::Nul(error)
+    }
+}
+
+impl From<std::str::Utf8Error> for ErrorCode {
+    fn from(error: std::str::Utf8Error) -> Self {
+        ErrorCode::Utf8(error)
+    }
+}
+
+/// The raw result of a call into the native bindings.
+pub use crate::bindings::ScInternalResult;
+
+#[cfg(all(feature = "backend", not(target_arch = "wasm32")))]
+impl ErrorCode {
+    /// Interprets a raw result returned by the native bindings. For
+    /// `ScInternalResult::CompilationError`, the message of the exception that caused it is
+    /// taken, so a given result should only be interpreted once.
+    pub fn from_raw(result: ScInternalResult) -> Result<(), ErrorCode> {
+        match result {
+            ScInternalResult::Success => Ok(()),
+            ScInternalResult::Unhandled => Err(ErrorCode::Unhandled),
+            ScInternalResult::CompilationError => unsafe {
+                let mut message_ptr = std::ptr::null();
+                if ScInternalResult::Success
+                    != bindings::sc_internal_get_latest_exception_message(&mut message_ptr)
+                    || message_ptr.is_null()
+                {
+                    return Err(ErrorCode::Unhandled);
+                }
+
+                let message = std::ffi::CStr::from_ptr(message_ptr)
+                    .to_owned()
+                    .into_string();
+
+                if ScInternalResult::Success
+                    != bindings::sc_internal_free_pointer(message_ptr as *mut std::os::raw::c_void)
+                {
+                    return Err(ErrorCode::Unhandled);
+                }
+
+                match message {
+                    Ok(message) => Err(ErrorCode::CompilationError(message)),
+                    Err(error) => Err(ErrorCode::Utf8(error.utf8_error())),
+                }
+            },
+        }
+    }
+}
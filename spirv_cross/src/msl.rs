@@ -1,11 +1,59 @@
 use crate::bindings as br;
+use crate::ptr_util::{read_into_vec_from_ptr, read_string_from_ptr};
 use crate::{compiler, spirv, ErrorCode};
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
 use std::ptr;
 
+/// C++ and Metal keywords, types and functions that SPIRV-Cross renames or that Metal
+/// rejects when they are used as identifiers, excluding the vector, matrix and packed types
+/// matched by `is_reserved_msl_name`.
+#[rustfmt::skip]
+const RESERVED_NAMES: &[&str] = &[
+    "alignas", "alignof", "and", "and_eq", "array", "asm", "assert", "auto", "bias", "bitand",
+    "bitor", "bool", "break", "case", "catch", "char", "class", "compl", "compute", "const",
+    "const_cast", "constant", "constexpr", "continue", "decltype", "default", "delete",
+    "depth2d", "depth2d_array", "depth2d_ms", "depth2d_ms_array", "depthcube",
+    "depthcube_array", "device", "do", "double", "dynamic_cast", "else", "enum", "explicit",
+    "export", "extern", "false", "float", "fmax3", "fmid3", "fmin3", "for", "fragment",
+    "friend", "goto", "gradient2d", "gradient3d", "gradientcube", "half", "if", "inline",
+    "int", "is_function_constant_defined", "kernel", "level", "long", "main", "metal",
+    "min_lod_clamp", "mutable", "namespace", "new", "noexcept", "not", "not_eq", "nullptr",
+    "operator", "or", "or_eq", "private", "protected", "public", "register",
+    "reinterpret_cast", "return", "sampler", "saturate", "short", "signed", "sizeof", "static",
+    "static_assert", "static_cast", "struct", "switch", "template", "texture",
+    "texture1d", "texture1d_array", "texture2d", "texture2d_array", "texture2d_ms",
+    "texture2d_ms_array", "texture3d", "texture_buffer", "texturecube", "texturecube_array",
+    "this", "thread", "thread_local", "threadgroup", "threadgroup_imageblock", "throw", "true",
+    "try", "typedef", "typeid", "typename", "uchar", "uint", "ulong", "union", "unsigned",
+    "ushort", "using", "vertex", "virtual", "void", "volatile", "wchar_t", "while", "xor",
+    "xor_eq",
+];
+
+/// Returns whether `name` is reserved by MSL or, as SPIRV-Cross also applies those, GLSL.
+fn is_reserved_msl_name(name: &str) -> bool {
+    if RESERVED_NAMES.contains(&name) || spirv::is_reserved_glsl_name(name) {
+        return true;
+    }
+
+    let name = name.strip_prefix("packed_").unwrap_or(name);
+    let is_dimension = |c: &u8| (b'2'..=b'4').contains(c);
+    [
+        "bool", "char", "uchar", "short", "ushort", "int", "uint", "long", "ulong", "half",
+        "float",
+    ]
+    .iter()
+    .any(|scalar| match name.strip_prefix(scalar).map(str::as_bytes) {
+        Some([rows]) => is_dimension(rows),
+        Some([rows, b'x', columns]) => {
+            matches!(*scalar, "half" | "float") && is_dimension(rows) && is_dimension(columns)
+        }
+        _ => false,
+    })
+}
+
 /// A MSL target.
 #[derive(Debug, Clone)]
 pub enum Target {}
@@ -14,10 +62,43 @@ pub struct TargetData {
     vertex_attribute_overrides: Vec<br::spirv_cross::MSLShaderInterfaceVariable>,
     resource_binding_overrides: Vec<br::spirv_cross::MSLResourceBinding>,
     const_samplers: Vec<br::ScMslConstSamplerMapping>,
+    argument_buffer_device_address_spaces: BTreeMap<u32, bool>,
+    argument_buffer_descriptors: BTreeMap<(u32, u32), ArgumentBufferDescriptor>,
+    compiler_options: CompilerOptions,
 }
 
 impl spirv::Target for Target {
     type Data = TargetData;
+
+    fn is_reserved_name(name: &str) -> bool {
+        is_reserved_msl_name(name)
+    }
+}
+
+/// How a binding is laid out inside an argument buffer.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum ArgumentBufferDescriptor {
+    /// A dynamic uniform or storage buffer. Its offset is read from the dynamic offsets
+    /// buffer at `index`.
+    DynamicBuffer { index: u32 },
+    /// A uniform buffer whose contents are embedded directly in the argument buffer.
+    InlineUniformBlock,
+}
+
+/// A subpass input read from tile memory through a `[[color(n)]]` attachment.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct TileInput {
+    pub resource: spirv::Resource,
+    pub color_attachment: u32,
+}
+
+/// Resources that live in tile or threadgroup memory, as returned by `get_tile_resources`.
+#[derive(Debug, Clone, Default, Hash, Eq, PartialEq)]
+pub struct TileResources {
+    /// Subpass inputs emitted as framebuffer fetches rather than textures.
+    pub inputs: Vec<TileInput>,
+    /// Ids of the variables declared in `threadgroup` memory.
+    pub threadgroup_variables: Vec<u32>,
 }
 
 /// Location of a vertex attribute to override
@@ -54,9 +135,27 @@ pub struct ResourceBinding {
     pub buffer_id: u32,
     pub texture_id: u32,
     pub sampler_id: u32,
+    /// The number of elements in a descriptor array. Runtime-sized arrays in the SPIR-V
+    /// have no declared length, so this sets the length of the fixed-size MSL array
+    /// emitted for them. Ignored for arrays with a declared length unless argument
+    /// buffers are enabled.
     pub count: u32,
 }
 
+impl ResourceBinding {
+    /// Creates a binding for a descriptor array of `count` elements, to be combined with
+    /// the buffer, texture and sampler indices via struct update syntax.
+    pub fn array(count: u32) -> Self {
+        ResourceBinding {
+            base_type: SPIRType_BaseType::Unknown,
+            buffer_id: 0,
+            texture_id: 0,
+            sampler_id: 0,
+            count,
+        }
+    }
+}
+
 /// Location of a sampler binding to override
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct SamplerLocation {
@@ -126,6 +225,17 @@ pub struct SamplerData {
     pub bpc: u32,
 }
 
+/// Whether a pipeline using a shader rasterizes its output.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RasterState {
+    Enabled,
+    Disabled,
+    /// A branch on the specialization constant with this id guards every write to the
+    /// position, so whether anything is rasterized depends on its value.
+    DependsOnSpecConstant(u32),
+}
+
 /// A MSL shader platform.
 #[repr(u8)]
 #[allow(non_snake_case, non_camel_case_types)]
@@ -163,6 +273,17 @@ impl Version {
     }
 }
 
+/// How arrays held by value are emitted.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ArrayEmission {
+    /// Emit native C-style arrays.
+    Native,
+    /// Wrap arrays in the `spvUnsafeArray` template so they can be copied and returned.
+    Templated,
+    /// Defer to `force_native_arrays`.
+    Auto,
+}
+
 #[derive(Default, Debug, Clone, Hash, Eq, PartialEq)]
 pub struct CompilerVertexOptions {
     pub invert_y: bool,
@@ -179,6 +300,8 @@ pub struct CompilerOptions {
     pub version: Version,
     /// Vertex compiler options.
     pub vertex: CompilerVertexOptions,
+    /// Clip space conventions overriding `vertex.invert_y` and `vertex.transform_clip_space`.
+    pub clip_space: Option<spirv::ClipSpaceOptions>,
     /// The buffer index to use for swizzle.
     pub swizzle_buffer_index: u32,
     // The buffer index to use for indirect params.
@@ -205,6 +328,11 @@ pub struct CompilerOptions {
     pub enable_argument_buffers: bool,
     /// Whether to pad fragment output to have at least the number of components as the render pass.
     pub pad_fragment_output_components: bool,
+    /// The first buffer, texture and sampler index given to resources that have no entry in
+    /// `resource_binding_overrides`. Indices below it are left free for the application, and
+    /// indices taken by the overrides are skipped. Ignored when `enable_argument_buffers` is
+    /// set.
+    pub automatic_binding_base: u32,
     /// MSL resource bindings overrides.
     pub resource_binding_overrides: BTreeMap<ResourceBindingLocation, ResourceBinding>,
     /// MSL vertex attribute overrides.
@@ -212,11 +340,52 @@ pub struct CompilerOptions {
     /// MSL const sampler mappings.
     pub const_samplers: BTreeMap<SamplerLocation, SamplerData>,
     /// Whether to force native arrays (useful to workaround issues on some hardware).
+    /// Only used when `array_emission` is `ArrayEmission::Auto`.
     pub force_native_arrays: bool,
+    /// How arrays held by value are emitted.
+    pub array_emission: ArrayEmission,
     /// Whether to force all uninitialized variables to be initialized to zero.
     pub force_zero_initialized_variables: bool,
     /// Whether to force always emit resources which are part of argument buffers
     pub force_active_argument_buffer_resources: bool,
+    /// Whether to enable multiview rendering, deriving the view index from the instance index.
+    pub multiview: bool,
+    /// Whether multiview renders each view to a separate layer of the render target.
+    pub multiview_layered_rendering: bool,
+    /// The buffer index to use for the view mask when multiview is enabled.
+    pub view_mask_buffer_index: u32,
+    /// The device index to use for the device index built-in.
+    pub device_index: u32,
+    /// Whether to emit texel buffers as native `texture_buffer` types (requires MSL 2.1)
+    /// rather than emulating them with 2D textures.
+    pub texture_buffer_native: bool,
+    /// Whether to emit `sample_compare` with an explicit LOD on depth texture arrays as a
+    /// gradient, to work around drivers that mishandle the LOD form.
+    pub sample_dref_lod_array_as_grad: bool,
+    /// Whether to support the base vertex and base instance built-ins on iOS, which requires
+    /// iOS 9 or later and a GPU family that supports them.
+    pub ios_support_base_vertex_instance: bool,
+    /// Whether to emulate subgroup operations with a subgroup size of 1, for Metal versions
+    /// and devices without SIMD-group support.
+    pub emulate_subgroups: bool,
+    /// The subgroup size to assume instead of querying it at runtime. Ignored if 0 or if
+    /// `emulate_subgroups` is set.
+    pub fixed_subgroup_size: u32,
+    /// Whether to emulate cube map arrays with 2D texture arrays of six faces per cube, for
+    /// iOS devices that don't support `texturecube_array`.
+    pub emulate_cube_array: bool,
+    /// The row alignment, in bytes, of linear textures that `r32ui` texel buffers with atomic
+    /// operations are backed by. Metal lacks atomics on textures, so such images are accessed
+    /// through an aliasing buffer.
+    pub r32ui_linear_texture_alignment: u32,
+    /// The id of a specialization constant that overrides `r32ui_linear_texture_alignment`
+    /// at pipeline creation time.
+    pub r32ui_alignment_constant_id: u32,
+    /// Options shared with the other targets.
+    pub common: spirv::CommonOptions,
+    /// Whether to append an `/* id=N */` comment to variable declarations, giving the SPIR-V
+    /// id each variable was emitted from. Intended for debugging generated shaders.
+    pub annotate_ids: bool,
     /// The name and execution model of the entry point to use. If no entry
     /// point is specified, then the first entry point found will be used.
     pub entry_point: Option<(String, spirv::ExecutionModel)>,
@@ -228,6 +397,7 @@ impl Default for CompilerOptions {
             platform: Platform::macOS,
             version: Version::V1_2,
             vertex: CompilerVertexOptions::default(),
+            clip_space: None,
             swizzle_buffer_index: 30,
             indirect_params_buffer_index: 29,
             output_buffer_index: 28,
@@ -241,12 +411,28 @@ impl Default for CompilerOptions {
             tessellation_domain_origin_lower_left: false,
             enable_argument_buffers: false,
             pad_fragment_output_components: false,
+            automatic_binding_base: 0,
             resource_binding_overrides: Default::default(),
             vertex_attribute_overrides: Default::default(),
             const_samplers: Default::default(),
             force_native_arrays: false,
+            array_emission: ArrayEmission::Auto,
             force_zero_initialized_variables: false,
             force_active_argument_buffer_resources: false,
+            multiview: false,
+            multiview_layered_rendering: true,
+            view_mask_buffer_index: 24,
+            device_index: 0,
+            texture_buffer_native: false,
+            sample_dref_lod_array_as_grad: false,
+            ios_support_base_vertex_instance: false,
+            emulate_subgroups: false,
+            fixed_subgroup_size: 0,
+            emulate_cube_array: false,
+            r32ui_linear_texture_alignment: 4,
+            r32ui_alignment_constant_id: 65535,
+            common: spirv::CommonOptions::default(),
+            annotate_ids: false,
             entry_point: None,
         }
     }
@@ -254,6 +440,8 @@ impl Default for CompilerOptions {
 
 impl spirv::Parse<Target> for spirv::Ast<Target> {
     fn parse(module: &spirv::Module) -> Result<Self, ErrorCode> {
+        module.validate_header()?;
+
         let mut sc_compiler = ptr::null_mut();
         unsafe {
             check!(br::sc_internal_compiler_msl_new(
@@ -270,8 +458,15 @@ impl spirv::Parse<Target> for spirv::Ast<Target> {
                     resource_binding_overrides: Vec::new(),
                     vertex_attribute_overrides: Vec::new(),
                     const_samplers: Vec::new(),
+                    argument_buffer_device_address_spaces: BTreeMap::new(),
+                    argument_buffer_descriptors: BTreeMap::new(),
+                    compiler_options: CompilerOptions::default(),
                 },
                 has_been_compiled: false,
+                annotate_ids: false,
+                type_cache: Default::default(),
+                compile_cache: Default::default(),
+                enabled_interface_variables: None,
             },
             target_type: PhantomData,
         })
@@ -283,20 +478,29 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
 
     /// Set MSL compiler specific compilation settings.
     fn set_compiler_options(&mut self, options: &CompilerOptions) -> Result<(), ErrorCode> {
-        if let Some((name, model)) = &options.entry_point {
-            let name_raw = CString::new(name.as_str()).map_err(|_| ErrorCode::Unhandled)?;
-            let model = *model;
-            unsafe {
-                check!(br::sc_internal_compiler_set_entry_point(
-                    self.compiler.sc_compiler,
-                    name_raw.as_ptr(),
-                    model,
-                ));
+        for (loc, data) in &options.const_samplers {
+            let addresses = [data.s_address, data.t_address, data.r_address];
+            if data.border_color != SamplerBorderColor::TransparentBlack
+                && !addresses.contains(&SamplerAddress::ClampToBorder)
+            {
+                return Err(ErrorCode::CompilationError(format!(
+                    "const sampler at set {}, binding {} has border color {:?}, which requires an \
+                     address mode of `ClampToBorder`",
+                    loc.desc_set, loc.binding, data.border_color
+                )));
             }
+        }
+
+        if let Some((name, model)) = &options.entry_point {
+            self.select_entry_point(name, *model)?;
+        };
+        let (vertex_invert_y, vertex_transform_clip_space) = match options.clip_space {
+            Some(clip_space) => (clip_space.flip_y, !clip_space.zero_to_one_depth),
+            None => (options.vertex.invert_y, options.vertex.transform_clip_space),
         };
         let raw_options = br::ScMslCompilerOptions {
-            vertex_invert_y: options.vertex.invert_y,
-            vertex_transform_clip_space: options.vertex.transform_clip_space,
+            vertex_invert_y,
+            vertex_transform_clip_space,
             platform: options.platform as _,
             version: options.version.as_raw(),
             enable_point_size_builtin: options.enable_point_size_builtin,
@@ -312,9 +516,25 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
             tess_domain_origin_lower_left: options.tessellation_domain_origin_lower_left,
             argument_buffers: options.enable_argument_buffers,
             pad_fragment_output_components: options.pad_fragment_output_components,
-            force_native_arrays: options.force_native_arrays,
+            force_native_arrays: match options.array_emission {
+                ArrayEmission::Native => true,
+                ArrayEmission::Templated => false,
+                ArrayEmission::Auto => options.force_native_arrays,
+            },
             force_zero_initialized_variables: options.force_zero_initialized_variables,
             force_active_argument_buffer_resources: options.force_active_argument_buffer_resources,
+            multiview: options.multiview,
+            multiview_layered_rendering: options.multiview_layered_rendering,
+            view_mask_buffer_index: options.view_mask_buffer_index,
+            device_index: options.device_index,
+            texture_buffer_native: options.texture_buffer_native,
+            sample_dref_lod_array_as_grad: options.sample_dref_lod_array_as_grad,
+            ios_support_base_vertex_instance: options.ios_support_base_vertex_instance,
+            emulate_subgroups: options.emulate_subgroups,
+            fixed_subgroup_size: options.fixed_subgroup_size,
+            emulate_cube_array: options.emulate_cube_array,
+            r32ui_linear_texture_alignment: options.r32ui_linear_texture_alignment,
+            r32ui_alignment_constant_id: options.r32ui_alignment_constant_id,
         };
         unsafe {
             check!(br::sc_internal_compiler_msl_set_options(
@@ -392,6 +612,12 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
                 }
             ));
 
+        self.compiler.set_common_options(&options.common)?;
+
+        self.compiler.annotate_ids = options.annotate_ids;
+
+        self.compiler.target_data.compiler_options = options.clone();
+
         Ok(())
     }
 
@@ -401,12 +627,214 @@ impl spirv::Compile<Target> for spirv::Ast<Target> {
     }
 }
 
+/// Finds a specialization constant guarding every store to the position, by scanning the
+/// instructions of `words`. A store is guarded when it is only reached through one arm of a
+/// selection whose condition is the specialization constant.
+fn find_rasterization_spec_constant(words: &[u32]) -> Option<u32> {
+    const OP_DECORATE: u32 = 71;
+    const OP_MEMBER_DECORATE: u32 = 72;
+    const OP_TYPE_POINTER: u32 = 32;
+    const OP_CONSTANT: u32 = 43;
+    const OP_SPEC_CONSTANT_TRUE: u32 = 48;
+    const OP_SPEC_CONSTANT_FALSE: u32 = 49;
+    const OP_SPEC_CONSTANT_OP: u32 = 52;
+    const OP_VARIABLE: u32 = 59;
+    const OP_STORE: u32 = 62;
+    const OP_ACCESS_CHAIN: u32 = 65;
+    const OP_IN_BOUNDS_ACCESS_CHAIN: u32 = 66;
+    const OP_SELECTION_MERGE: u32 = 247;
+    const OP_LABEL: u32 = 248;
+    const OP_BRANCH: u32 = 249;
+    const OP_BRANCH_CONDITIONAL: u32 = 250;
+    const OP_SWITCH: u32 = 251;
+    const DECORATION_BUILT_IN: u32 = 11;
+    const BUILT_IN_POSITION: u32 = 0;
+
+    #[derive(Default)]
+    struct Block {
+        successors: Vec<u32>,
+        merge: Option<u32>,
+        condition: Option<u32>,
+        writes_position: bool,
+    }
+
+    let instructions = || {
+        let mut offset = spirv::HEADER_WORD_COUNT;
+        std::iter::from_fn(move || {
+            let word_count = (*words.get(offset)? >> 16) as usize;
+            let instruction = words.get(offset..offset + word_count.max(1))?;
+            offset += word_count.max(1);
+            Some((instruction[0] & 0xffff, &instruction[1..]))
+        })
+    };
+
+    let mut spec_constants = HashSet::new();
+    let mut position_pointers = HashSet::new();
+    let mut position_members = HashSet::new();
+    let mut pointee_types = HashMap::new();
+    let mut variable_types = HashMap::new();
+    let mut constants = HashMap::new();
+    for (opcode, operands) in instructions() {
+        match (opcode, operands) {
+            (OP_DECORATE, &[target, DECORATION_BUILT_IN, BUILT_IN_POSITION]) => {
+                position_pointers.insert(target);
+            }
+            (OP_MEMBER_DECORATE, &[ty, member, DECORATION_BUILT_IN, BUILT_IN_POSITION]) => {
+                position_members.insert((ty, member));
+            }
+            (OP_TYPE_POINTER, &[id, _, pointee]) => {
+                pointee_types.insert(id, pointee);
+            }
+            (OP_CONSTANT, &[_, id, value, ..]) => {
+                constants.insert(id, value);
+            }
+            (OP_SPEC_CONSTANT_TRUE, &[_, id])
+            | (OP_SPEC_CONSTANT_FALSE, &[_, id])
+            | (OP_SPEC_CONSTANT_OP, &[_, id, ..]) => {
+                spec_constants.insert(id);
+            }
+            (OP_VARIABLE, &[ty, id, ..]) => {
+                variable_types.insert(id, ty);
+            }
+            (OP_ACCESS_CHAIN, &[_, id, base, index, ..])
+            | (OP_IN_BOUNDS_ACCESS_CHAIN, &[_, id, base, index, ..]) => {
+                let block_type = variable_types
+                    .get(&base)
+                    .and_then(|ty| pointee_types.get(ty));
+                if let (Some(&block_type), Some(&member)) = (block_type, constants.get(&index)) {
+                    if position_members.contains(&(block_type, member)) {
+                        position_pointers.insert(id);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut blocks = HashMap::new();
+    let mut current = None;
+    for (opcode, operands) in instructions() {
+        if opcode == OP_LABEL {
+            current = operands.first().copied();
+            continue;
+        }
+        let block: &mut Block = match current {
+            Some(label) => blocks.entry(label).or_default(),
+            None => continue,
+        };
+        match (opcode, operands) {
+            (OP_STORE, &[pointer, ..]) if position_pointers.contains(&pointer) => {
+                block.writes_position = true;
+            }
+            (OP_SELECTION_MERGE, &[merge, ..]) => block.merge = Some(merge),
+            (OP_BRANCH, &[target]) => block.successors.push(target),
+            (OP_BRANCH_CONDITIONAL, &[condition, true_label, false_label, ..]) => {
+                block.condition = Some(condition);
+                block
+                    .successors
+                    .extend_from_slice(&[true_label, false_label]);
+            }
+            (OP_SWITCH, &[_, default, ref targets @ ..]) => {
+                block.successors.push(default);
+                block
+                    .successors
+                    .extend(targets.iter().skip(1).step_by(2).copied());
+            }
+            _ => {}
+        }
+    }
+
+    let writes = blocks
+        .iter()
+        .filter(|(_, block)| block.writes_position)
+        .map(|(&label, _)| label)
+        .collect::<HashSet<_>>();
+    if writes.is_empty() {
+        return None;
+    }
+
+    // Gets the blocks reached from `start` before `merge`.
+    let reachable = |start: u32, merge: u32| {
+        let mut visited = HashSet::new();
+        let mut pending = vec![start];
+        while let Some(label) = pending.pop() {
+            if label == merge || !visited.insert(label) {
+                continue;
+            }
+            if let Some(block) = blocks.get(&label) {
+                pending.extend_from_slice(&block.successors);
+            }
+        }
+        visited
+    };
+
+    blocks
+        .values()
+        .filter_map(|header| {
+            let condition = header.condition.filter(|id| spec_constants.contains(id))?;
+            let merge = header.merge?;
+            let (true_label, false_label) = match *header.successors.as_slice() {
+                [true_label, false_label] => (true_label, false_label),
+                _ => return None,
+            };
+            let true_blocks = reachable(true_label, merge);
+            let false_blocks = reachable(false_label, merge);
+            let is_guarded_by = |arm: &HashSet<u32>, other: &HashSet<u32>| {
+                writes
+                    .iter()
+                    .all(|label| arm.contains(label) && !other.contains(label))
+            };
+            if is_guarded_by(&true_blocks, &false_blocks)
+                || is_guarded_by(&false_blocks, &true_blocks)
+            {
+                Some(condition)
+            } else {
+                None
+            }
+        })
+        .min()
+}
+
+/// The descriptor set and binding SPIRV-Cross uses to match push constant overrides.
+const PUSH_CONSTANT_DESC_SET: u32 = !0;
+const PUSH_CONSTANT_BINDING: u32 = 0;
+
 impl spirv::Ast<Target> {
     fn compile_internal(&self) -> Result<String, ErrorCode> {
         let vat_overrides = &self.compiler.target_data.vertex_attribute_overrides;
-        let res_overrides = &self.compiler.target_data.resource_binding_overrides;
+        let res_overrides = self.effective_resource_binding_overrides()?;
         let const_samplers = &self.compiler.target_data.const_samplers;
+        let device_address_spaces = &self.compiler.target_data.argument_buffer_device_address_spaces;
+        let descriptors = &self.compiler.target_data.argument_buffer_descriptors;
         unsafe {
+            for (&desc_set, &device_storage) in device_address_spaces {
+                check!(br::sc_internal_compiler_msl_set_argument_buffer_device_address_space(
+                    self.compiler.sc_compiler,
+                    desc_set,
+                    device_storage,
+                ));
+            }
+
+            for (&(desc_set, binding), descriptor) in descriptors {
+                match *descriptor {
+                    ArgumentBufferDescriptor::DynamicBuffer { index } => {
+                        check!(br::sc_internal_compiler_msl_add_dynamic_buffer(
+                            self.compiler.sc_compiler,
+                            desc_set,
+                            binding,
+                            index,
+                        ));
+                    }
+                    ArgumentBufferDescriptor::InlineUniformBlock => {
+                        check!(br::sc_internal_compiler_msl_add_inline_uniform_block(
+                            self.compiler.sc_compiler,
+                            desc_set,
+                            binding,
+                        ));
+                    }
+                }
+            }
+
             let mut shader_ptr = ptr::null();
             check!(br::sc_internal_compiler_msl_compile(
                 self.compiler.sc_compiler,
@@ -420,15 +848,195 @@ impl spirv::Ast<Target> {
             ));
             let shader = match CStr::from_ptr(shader_ptr).to_str() {
                 Ok(v) => v.to_owned(),
-                Err(_) => return Err(ErrorCode::Unhandled),
+                Err(error) => return Err(error.into()),
             };
             check!(br::sc_internal_free_pointer(
                 shader_ptr as *mut std::os::raw::c_void
             ));
+            if self.compiler.annotate_ids {
+                return self.compiler.annotate_shader_ids(&shader);
+            }
             Ok(shader)
         }
     }
 
+    /// The resource binding overrides passed to SPIRV-Cross when compiling, including those
+    /// assigned from `CompilerOptions::automatic_binding_base`.
+    fn effective_resource_binding_overrides(
+        &self,
+    ) -> Result<Vec<br::spirv_cross::MSLResourceBinding>, ErrorCode> {
+        let options = &self.compiler.target_data.compiler_options;
+        let mut res_overrides = self.compiler.target_data.resource_binding_overrides.clone();
+        if options.automatic_binding_base != 0 && !options.enable_argument_buffers {
+            let automatic = self.automatic_resource_bindings(options.automatic_binding_base)?;
+            res_overrides.extend(automatic);
+        }
+
+        Ok(res_overrides)
+    }
+
+    /// Assigns indices starting at `base` to every resource without an explicit override, in
+    /// place of SPIRV-Cross's own allocator which always starts at 0. Indices taken by the
+    /// explicit overrides of the stage's resources are skipped.
+    fn automatic_resource_bindings(
+        &self,
+        base: u32,
+    ) -> Result<Vec<br::spirv_cross::MSLResourceBinding>, ErrorCode> {
+        let stage = self.compiler.get_execution_model()?;
+        let overrides = &self.compiler.target_data.resource_binding_overrides;
+
+        let mut resources = Vec::new();
+        let mut taken_buffers = Vec::new();
+        let mut taken_textures = Vec::new();
+        let mut taken_samplers = Vec::new();
+        for info in self.resources_with_bindings()? {
+            let (uses_buffer, uses_texture, uses_sampler) = match info.kind {
+                spirv::ResourceKind::UniformBuffer
+                | spirv::ResourceKind::StorageBuffer
+                | spirv::ResourceKind::AccelerationStructure
+                | spirv::ResourceKind::PushConstantBuffer => (true, false, false),
+                spirv::ResourceKind::SampledImage => (false, true, true),
+                spirv::ResourceKind::SubpassInput
+                | spirv::ResourceKind::StorageImage
+                | spirv::ResourceKind::SeparateImage => (false, true, false),
+                spirv::ResourceKind::SeparateSampler => (false, false, true),
+                spirv::ResourceKind::StageInput
+                | spirv::ResourceKind::StageOutput
+                | spirv::ResourceKind::AtomicCounter
+                | spirv::ResourceKind::ShaderRecordBuffer => continue,
+            };
+            let (desc_set, binding) = if info.kind == spirv::ResourceKind::PushConstantBuffer {
+                (PUSH_CONSTANT_DESC_SET, PUSH_CONSTANT_BINDING)
+            } else {
+                (info.set.unwrap_or(0), info.binding.unwrap_or(0))
+            };
+            let count = self.descriptor_count(info.resource.type_id)?;
+            let explicit = overrides
+                .iter()
+                .find(|o| o.stage == stage && o.desc_set == desc_set && o.binding == binding);
+            match explicit {
+                Some(o) => {
+                    let end = |start: u32| start.saturating_add(count.max(o.count).max(1));
+                    if uses_buffer {
+                        taken_buffers.push(o.msl_buffer..end(o.msl_buffer));
+                    }
+                    if uses_texture {
+                        taken_textures.push(o.msl_texture..end(o.msl_texture));
+                    }
+                    if uses_sampler {
+                        taken_samplers.push(o.msl_sampler..end(o.msl_sampler));
+                    }
+                }
+                None => resources.push((
+                    desc_set,
+                    binding,
+                    count,
+                    uses_buffer,
+                    uses_texture,
+                    uses_sampler,
+                )),
+            }
+        }
+
+        // Gets the first index from `next` onwards with `count` indices free of `taken`.
+        fn allocate(next: &mut u32, taken: &[std::ops::Range<u32>], count: u32) -> u32 {
+            let mut start = *next;
+            while let Some(range) = taken
+                .iter()
+                .find(|range| range.start < start + count.max(1) && start < range.end)
+            {
+                start = range.end;
+            }
+            *next = start + count;
+            start
+        }
+
+        let mut next_buffer = base;
+        let mut next_texture = base;
+        let mut next_sampler = base;
+        let mut bindings = Vec::new();
+        for (desc_set, binding, count, uses_buffer, uses_texture, uses_sampler) in resources {
+            let mut resource_binding = br::spirv_cross::MSLResourceBinding {
+                stage,
+                basetype: SPIRType_BaseType::Unknown,
+                desc_set,
+                binding,
+                count,
+                msl_buffer: 0,
+                msl_texture: 0,
+                msl_sampler: 0,
+            };
+            if uses_buffer {
+                resource_binding.msl_buffer = allocate(&mut next_buffer, &taken_buffers, count);
+            }
+            if uses_texture {
+                resource_binding.msl_texture = allocate(&mut next_texture, &taken_textures, count);
+            }
+            if uses_sampler {
+                resource_binding.msl_sampler = allocate(&mut next_sampler, &taken_samplers, count);
+            }
+            bindings.push(resource_binding);
+        }
+        Ok(bindings)
+    }
+
+    /// Compiles to `version`, overriding `CompilerOptions::version` for this call only. The
+    /// previously set version is restored afterwards, even if compilation fails.
+    pub fn compile_version(&mut self, version: Version) -> Result<String, ErrorCode> {
+        let mut previous_version = 0;
+        unsafe {
+            check!(br::sc_internal_compiler_msl_get_version(
+                self.compiler.sc_compiler,
+                &mut previous_version,
+            ));
+            check!(br::sc_internal_compiler_msl_set_version(
+                self.compiler.sc_compiler,
+                version.as_raw(),
+            ));
+        }
+
+        let shader = self.compile();
+
+        unsafe {
+            check!(br::sc_internal_compiler_msl_set_version(
+                self.compiler.sc_compiler,
+                previous_version,
+            ));
+        }
+
+        shader
+    }
+
+    /// Gets the options last applied with `set_compiler_options`, or the default options if
+    /// none have been applied.
+    pub fn get_compiler_options(&self) -> CompilerOptions {
+        self.compiler.target_data.compiler_options.clone()
+    }
+
+    /// Gets the resources that MSL places in tile or threadgroup memory. Subpass inputs are
+    /// only read from tile memory on iOS, so `inputs` is empty for the macOS platform of the
+    /// options last applied with `set_compiler_options`.
+    pub fn get_tile_resources(&self) -> Result<TileResources, ErrorCode> {
+        let mut tile_resources = TileResources::default();
+        if self.compiler.target_data.compiler_options.platform == Platform::iOS {
+            for resource in self.compiler.get_shader_resources()?.subpass_inputs {
+                let color_attachment = self.get_input_attachment_index(resource.id)?;
+                tile_resources.inputs.push(TileInput {
+                    resource,
+                    color_attachment,
+                });
+            }
+        }
+
+        for id in self.compiler.get_ids_for_type(spirv::IdType::Variable)? {
+            if self.compiler.get_variable_storage_class(id)? == spirv::StorageClass::Workgroup {
+                tile_resources.threadgroup_variables.push(id);
+            }
+        }
+
+        Ok(tile_resources)
+    }
+
     pub fn is_rasterization_enabled(&self) -> Result<bool, ErrorCode> {
         unsafe {
             let mut is_disabled = false;
@@ -440,6 +1048,112 @@ impl spirv::Ast<Target> {
         }
     }
 
+    /// Gets whether the compiled shader requires rasterization, taking into account both
+    /// `CompilerOptions::enable_rasterization` and vertex shaders which SPIRV-Cross determines
+    /// cannot rasterize, such as those which write no position. `compile` must be called first.
+    ///
+    /// For vertex and tessellation evaluation shaders that SPIRV-Cross rasterizes,
+    /// rasterization is reported as depending on a specialization constant when every write
+    /// to the position is only reached through one arm of a selection whose condition is that
+    /// specialization constant. Conditions computed from specialization constants inside a
+    /// function are not traced, and a `discard` does not affect the state.
+    pub fn rasterization_state(&self) -> Result<RasterState, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        if !self.is_rasterization_enabled()? {
+            return Ok(RasterState::Disabled);
+        }
+        match self.compiler.get_execution_model()? {
+            spirv::ExecutionModel::Vertex | spirv::ExecutionModel::TessellationEvaluation => {}
+            _ => return Ok(RasterState::Enabled),
+        }
+
+        let words = unsafe {
+            let mut words_ptr = ptr::null();
+            let mut words_len = 0;
+            check!(br::sc_internal_compiler_msl_get_spirv(
+                self.compiler.sc_compiler,
+                &mut words_ptr,
+                &mut words_len,
+            ));
+            read_into_vec_from_ptr(words_ptr, words_len)
+        };
+
+        Ok(match find_rasterization_spec_constant(&words) {
+            Some(id) => RasterState::DependsOnSpecConstant(id),
+            None => RasterState::Enabled,
+        })
+    }
+
+    /// Gets the layout of the buffer size buffer, bound at
+    /// `CompilerOptions::buffer_size_buffer_index`, as `(binding, offset)` pairs. Each pair is
+    /// the `[[buffer(n)]]` index of a storage buffer whose runtime array length is queried by the
+    /// shader, and the byte offset of the `uint` holding that buffer's size. `compile` must be
+    /// called first.
+    pub fn get_buffer_size_buffer_layout(&self) -> Result<Vec<(u32, u32)>, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        let stage = self.compiler.get_execution_model()?;
+        let res_overrides = self.effective_resource_binding_overrides()?;
+        let mut layout = Vec::new();
+        for resource in self.compiler.get_shader_resources()?.storage_buffers {
+            let mut requires_array_length = false;
+            unsafe {
+                check!(br::sc_internal_compiler_msl_buffer_requires_array_length(
+                    self.compiler.sc_compiler,
+                    resource.id,
+                    &mut requires_array_length,
+                ));
+            }
+
+            if !requires_array_length {
+                continue;
+            }
+
+            let desc_set = self
+                .compiler
+                .get_decoration(resource.id, spirv::Decoration::DescriptorSet)?;
+            let binding = self
+                .compiler
+                .get_decoration(resource.id, spirv::Decoration::Binding)?;
+            let msl_buffer = match res_overrides
+                .iter()
+                .find(|o| o.stage == stage && o.desc_set == desc_set && o.binding == binding)
+            {
+                Some(resource_binding) => resource_binding.msl_buffer,
+                None => match self.get_automatic_msl_resource_binding(resource.id)? {
+                    binding if binding != !0 => binding,
+                    _ => {
+                        return Err(ErrorCode::CompilationError(format!(
+                            "no buffer index was assigned to `{}`",
+                            resource.name
+                        )))
+                    }
+                },
+            };
+            let offset = msl_buffer
+                .checked_mul(std::mem::size_of::<u32>() as u32)
+                .ok_or_else(|| {
+                    ErrorCode::CompilationError(format!(
+                        "buffer index {} of `{}` is out of range",
+                        msl_buffer, resource.name
+                    ))
+                })?;
+            layout.push((msl_buffer, offset));
+        }
+        layout.sort_unstable();
+
+        Ok(layout)
+    }
+
     pub fn get_automatic_msl_resource_binding(&self, id: u32) -> Result<u32, ErrorCode> {
         unsafe {
             let mut res = 0;
@@ -451,6 +1165,109 @@ impl spirv::Ast<Target> {
             Ok(res)
         }
     }
+
+    /// Sets whether the argument buffer for `desc_set` is placed in the `device` address space
+    /// rather than the `constant` address space. Applied when compiling.
+    pub fn set_argument_buffer_device_address_space(&mut self, desc_set: u32, device: bool) {
+        self.clear_compile_cache();
+        self.compiler
+            .target_data
+            .argument_buffer_device_address_spaces
+            .insert(desc_set, device);
+    }
+
+    /// Sets how the resource at `desc_set` and `binding` is laid out in its argument buffer.
+    /// Only takes effect when argument buffers are enabled. Applied when compiling.
+    pub fn set_argument_buffer_descriptor(
+        &mut self,
+        desc_set: u32,
+        binding: u32,
+        kind: ArgumentBufferDescriptor,
+    ) {
+        self.clear_compile_cache();
+        self.compiler
+            .target_data
+            .argument_buffer_descriptors
+            .insert((desc_set, binding), kind);
+    }
+
+    /// Sets the suffix appended to the names of combined image samplers. Defaults to `Smplr`.
+    pub fn set_combined_sampler_suffix(&mut self, suffix: &str) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        let suffix = CString::new(suffix)?;
+        unsafe {
+            check!(br::sc_internal_compiler_msl_set_combined_sampler_suffix(
+                self.compiler.sc_compiler,
+                suffix.as_ptr(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Gets the suffix appended to the names of combined image samplers.
+    pub fn get_combined_sampler_suffix(&self) -> Result<String, ErrorCode> {
+        unsafe {
+            let mut suffix_ptr = ptr::null();
+            check!(br::sc_internal_compiler_msl_get_combined_sampler_suffix(
+                self.compiler.sc_compiler,
+                &mut suffix_ptr,
+            ));
+            let suffix = read_string_from_ptr(suffix_ptr)?;
+            check!(br::sc_internal_free_pointer(
+                suffix_ptr as *mut std::os::raw::c_void
+            ));
+            Ok(suffix)
+        }
+    }
+
+    /// Sets which fragment outputs are written, as a bit mask of `[[color(n)]]` locations.
+    /// Outputs whose bit is cleared are omitted from the fragment output struct, which is useful
+    /// when rendering to a subset of the render pass attachments. All outputs are enabled by
+    /// default.
+    pub fn set_enabled_fragment_outputs(&mut self, mask: u32) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        unsafe {
+            check!(br::sc_internal_compiler_msl_set_enabled_fragment_outputs(
+                self.compiler.sc_compiler,
+                mask,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Gets the name of the struct the current entry point returns its stage outputs in,
+    /// e.g. `main0_out`. `compile` must be called first.
+    pub fn get_stage_output_struct_name(&self) -> Result<String, ErrorCode> {
+        self.check_stage_output_struct()?;
+
+        let entry_point_name = self.compiler.get_entry_point_name()?;
+        let execution_model = self.compiler.get_execution_model()?;
+        let name = self
+            .compiler
+            .get_cleansed_entry_point_name(&entry_point_name, execution_model)?;
+        Ok(format!("{}_out", name))
+    }
+
+    /// Gets the number of plane textures a sampler expands into, as configured by a constant
+    /// sampler with YCbCr conversion enabled in `CompilerOptions::const_samplers`. Returns 1
+    /// for non-planar samplers.
+    pub fn get_sampler_plane_count(&self, id: u32) -> Result<u32, ErrorCode> {
+        let desc_set = self
+            .compiler
+            .get_decoration(id, spirv::Decoration::DescriptorSet)?;
+        let binding = self.compiler.get_decoration(id, spirv::Decoration::Binding)?;
+
+        Ok(self
+            .compiler
+            .target_data
+            .const_samplers
+            .iter()
+            .find(|mapping| mapping.desc_set == desc_set && mapping.binding == binding)
+            .filter(|mapping| mapping.sampler.ycbcr_conversion_enable)
+            .map_or(1, |mapping| mapping.sampler.planes.max(1)))
+    }
 }
 
 // TODO: Generate with bindgen
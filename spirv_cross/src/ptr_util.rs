@@ -4,6 +4,7 @@
 use crate::ErrorCode;
 #[allow(unused_imports)]
 use std::{
+    borrow::Cow,
     slice,
     ffi::CStr
 };
@@ -16,7 +17,7 @@ pub unsafe fn read_string_from_ptr(ptr: *const std::os::raw::c_char) -> Result<S
     let string = CStr::from_ptr(ptr)
         .to_owned()
         .into_string()
-        .map_err(|_| ErrorCode::Unhandled);
+        .map_err(|error| ErrorCode::Utf8(error.utf8_error()));
     #[cfg(target_arch = "wasm32")]
     let string = {
         let bytes = emscripten::get_module().read_bytes_into_vec_while(
@@ -24,11 +25,22 @@ pub unsafe fn read_string_from_ptr(ptr: *const std::os::raw::c_char) -> Result<S
             |byte, _| 0 != byte,
             false,
         );
-        String::from_utf8(bytes).map_err(|_| ErrorCode::Unhandled)
+        String::from_utf8(bytes).map_err(|error| ErrorCode::Utf8(error.utf8_error()))
     };
     string
 }
 
+/// Reads a string like `read_string_from_ptr`, without allocating if it is empty.
+pub unsafe fn read_cow_from_ptr(
+    ptr: *const std::os::raw::c_char,
+) -> Result<Cow<'static, str>, ErrorCode> {
+    if read_from_ptr(ptr) == 0 {
+        Ok(Cow::Borrowed(""))
+    } else {
+        read_string_from_ptr(ptr).map(Cow::Owned)
+    }
+}
+
 pub unsafe fn read_from_ptr<T>(ptr: *const T) -> T {
     #[cfg(not(target_arch = "wasm32"))]
     let value = ptr.read();
@@ -1,6 +1,19 @@
 use std::collections::HashSet;
-use crate::{compiler, ErrorCode};
+#[cfg(feature = "backend")]
+use std::collections::{BTreeMap, BTreeSet};
+#[cfg(feature = "backend")]
+use crate::compiler;
+use crate::ErrorCode;
+#[cfg(feature = "backend")]
 use std::marker::PhantomData;
+#[cfg(feature = "backend")]
+use std::borrow::Cow;
+#[cfg(feature = "backend")]
+use std::collections::hash_map::DefaultHasher;
+#[cfg(feature = "backend")]
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "backend")]
+use std::rc::Rc;
 
 /// A stage or compute kernel.
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
@@ -8,6 +21,8 @@ pub struct CombinedImageSampler {
     pub combined_id: u32,
     pub image_id: u32,
     pub sampler_id: u32,
+    /// Whether the image is a depth image, so the sampler must be a comparison sampler.
+    pub comparison: bool,
 }
 
 /// A stage or compute kernel.
@@ -16,7 +31,14 @@ pub use crate::bindings::spv::ExecutionModel;
 /// A decoration.
 pub use crate::bindings::spv::Decoration;
 
+/// A storage class.
+pub use crate::bindings::spv::StorageClass;
+
+/// A capability.
+pub use crate::bindings::spv::Capability;
+
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BuiltIn {
     Position,
     PointSize,
@@ -124,9 +146,10 @@ pub enum BuiltIn {
     CullMaskKhr,
 }
 
+#[cfg(feature = "backend")]
 impl BuiltIn {
-    pub(crate) fn from_raw(v: spv::BuiltIn) -> Self {
-        match v {
+    pub(crate) fn from_raw(v: spv::BuiltIn) -> Result<Self, ErrorCode> {
+        Ok(match v {
             spv::BuiltIn::Position => Self::Position,
             spv::BuiltIn::PointSize => Self::PointSize,
             spv::BuiltIn::ClipDistance => Self::ClipDistance,
@@ -231,12 +254,17 @@ impl BuiltIn {
             spv::BuiltIn::WarpIdnv => Self::WarpIdnv,
             spv::BuiltIn::Smidnv => Self::Smidnv,
             spv::BuiltIn::CullMaskKhr => Self::CullMaskKhr,
-            spv::BuiltIn::Max => unreachable!("invalid builtin")
-        }
+            spv::BuiltIn::Max => {
+                return Err(ErrorCode::CompilationError(format!(
+                    "invalid builtin {}",
+                    v as u32
+                )))
+            }
+        })
     }
 }
 
-#[cfg(feature = "msl")]
+#[cfg(feature = "backend")]
 pub(crate) fn built_in_as_raw(built_in: Option<BuiltIn>) -> crate::bindings::spv::BuiltIn {
     use crate::bindings as br;
     use BuiltIn::*;
@@ -349,8 +377,281 @@ pub(crate) fn built_in_as_raw(built_in: Option<BuiltIn>) -> crate::bindings::spv
     }
 }
 
+/// Implements `Display` and `FromStr` using the canonical SPIR-V names of the variants.
+/// Parsing ignores ASCII case, so that both `Vertex` and `vertex` are accepted.
+macro_rules! impl_spirv_names {
+    ($ty:ident { $($variant:ident => $name:literal,)* }) => {
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(match *self {
+                    $($ty::$variant => $name,)*
+                })
+            }
+        }
+
+        impl std::str::FromStr for $ty {
+            type Err = ErrorCode;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    if s.eq_ignore_ascii_case($name) {
+                        return Ok($ty::$variant);
+                    }
+                )*
+                Err(ErrorCode::CompilationError(format!(
+                    "unknown {} `{}`",
+                    stringify!($ty),
+                    s
+                )))
+            }
+        }
+    };
+}
+
+impl_spirv_names!(ExecutionModel {
+    Vertex => "Vertex",
+    TessellationControl => "TessellationControl",
+    TessellationEvaluation => "TessellationEvaluation",
+    Geometry => "Geometry",
+    Fragment => "Fragment",
+    GlCompute => "GLCompute",
+    Kernel => "Kernel",
+    TaskNv => "TaskNV",
+    MeshNv => "MeshNV",
+    RayGenerationKhr => "RayGenerationKHR",
+    IntersectionKhr => "IntersectionKHR",
+    AnyHitKhr => "AnyHitKHR",
+    ClosestHitKhr => "ClosestHitKHR",
+    MissKhr => "MissKHR",
+    CallableKhr => "CallableKHR",
+    TaskExt => "TaskEXT",
+    MeshExt => "MeshEXT",
+});
+
+impl_spirv_names!(Decoration {
+    RelaxedPrecision => "RelaxedPrecision",
+    SpecId => "SpecId",
+    Block => "Block",
+    BufferBlock => "BufferBlock",
+    RowMajor => "RowMajor",
+    ColMajor => "ColMajor",
+    ArrayStride => "ArrayStride",
+    MatrixStride => "MatrixStride",
+    GlslShared => "GLSLShared",
+    GlslPacked => "GLSLPacked",
+    CPacked => "CPacked",
+    BuiltIn => "BuiltIn",
+    NoPerspective => "NoPerspective",
+    Flat => "Flat",
+    Patch => "Patch",
+    Centroid => "Centroid",
+    Sample => "Sample",
+    Invariant => "Invariant",
+    Restrict => "Restrict",
+    Aliased => "Aliased",
+    Volatile => "Volatile",
+    Constant => "Constant",
+    Coherent => "Coherent",
+    NonWritable => "NonWritable",
+    NonReadable => "NonReadable",
+    Uniform => "Uniform",
+    UniformId => "UniformId",
+    SaturatedConversion => "SaturatedConversion",
+    Stream => "Stream",
+    Location => "Location",
+    Component => "Component",
+    Index => "Index",
+    Binding => "Binding",
+    DescriptorSet => "DescriptorSet",
+    Offset => "Offset",
+    XfbBuffer => "XfbBuffer",
+    XfbStride => "XfbStride",
+    FuncParamAttr => "FuncParamAttr",
+    FpRoundingMode => "FPRoundingMode",
+    FpFastMathMode => "FPFastMathMode",
+    LinkageAttributes => "LinkageAttributes",
+    NoContraction => "NoContraction",
+    InputAttachmentIndex => "InputAttachmentIndex",
+    Alignment => "Alignment",
+    MaxByteOffset => "MaxByteOffset",
+    AlignmentId => "AlignmentId",
+    MaxByteOffsetId => "MaxByteOffsetId",
+    NoSignedWrap => "NoSignedWrap",
+    NoUnsignedWrap => "NoUnsignedWrap",
+    ExplicitInterpAmd => "ExplicitInterpAMD",
+    OverrideCoverageNv => "OverrideCoverageNV",
+    PassthroughNv => "PassthroughNV",
+    ViewportRelativeNv => "ViewportRelativeNV",
+    SecondaryViewportRelativeNv => "SecondaryViewportRelativeNV",
+    PerPrimitiveExt => "PerPrimitiveEXT",
+    PerViewNv => "PerViewNV",
+    PerTaskNv => "PerTaskNV",
+    PerVertexKhr => "PerVertexKHR",
+    NonUniform => "NonUniform",
+    RestrictPointer => "RestrictPointer",
+    AliasedPointer => "AliasedPointer",
+    BindlessSamplerNv => "BindlessSamplerNV",
+    BindlessImageNv => "BindlessImageNV",
+    BoundSamplerNv => "BoundSamplerNV",
+    BoundImageNv => "BoundImageNV",
+    SimtCallIntel => "SIMTCallINTEL",
+    ReferencedIndirectlyIntel => "ReferencedIndirectlyINTEL",
+    ClobberIntel => "ClobberINTEL",
+    SideEffectsIntel => "SideEffectsINTEL",
+    VectorComputeVariableIntel => "VectorComputeVariableINTEL",
+    FuncParamIoKindIntel => "FuncParamIOKindINTEL",
+    VectorComputeFunctionIntel => "VectorComputeFunctionINTEL",
+    StackCallIntel => "StackCallINTEL",
+    GlobalVariableOffsetIntel => "GlobalVariableOffsetINTEL",
+    CounterBuffer => "CounterBuffer",
+    HlslSemanticGoogle => "HlslSemanticGOOGLE",
+    UserTypeGoogle => "UserTypeGOOGLE",
+    FunctionRoundingModeIntel => "FunctionRoundingModeINTEL",
+    FunctionDenormModeIntel => "FunctionDenormModeINTEL",
+    RegisterIntel => "RegisterINTEL",
+    MemoryIntel => "MemoryINTEL",
+    NumbanksIntel => "NumbanksINTEL",
+    BankwidthIntel => "BankwidthINTEL",
+    MaxPrivateCopiesIntel => "MaxPrivateCopiesINTEL",
+    SinglepumpIntel => "SinglepumpINTEL",
+    DoublepumpIntel => "DoublepumpINTEL",
+    MaxReplicatesIntel => "MaxReplicatesINTEL",
+    SimpleDualPortIntel => "SimpleDualPortINTEL",
+    MergeIntel => "MergeINTEL",
+    BankBitsIntel => "BankBitsINTEL",
+    ForcePow2DepthIntel => "ForcePow2DepthINTEL",
+    BurstCoalesceIntel => "BurstCoalesceINTEL",
+    CacheSizeIntel => "CacheSizeINTEL",
+    DontStaticallyCoalesceIntel => "DontStaticallyCoalesceINTEL",
+    PrefetchIntel => "PrefetchINTEL",
+    StallEnableIntel => "StallEnableINTEL",
+    FuseLoopsInFunctionIntel => "FuseLoopsInFunctionINTEL",
+    AliasScopeIntel => "AliasScopeINTEL",
+    NoAliasIntel => "NoAliasINTEL",
+    BufferLocationIntel => "BufferLocationINTEL",
+    IoPipeStorageIntel => "IOPipeStorageINTEL",
+    FunctionFloatingPointModeIntel => "FunctionFloatingPointModeINTEL",
+    SingleElementVectorIntel => "SingleElementVectorINTEL",
+    VectorComputeCallableFunctionIntel => "VectorComputeCallableFunctionINTEL",
+    MediaBlockIointel => "MediaBlockIOINTEL",
+});
+
+impl_spirv_names!(BuiltIn {
+    Position => "Position",
+    PointSize => "PointSize",
+    ClipDistance => "ClipDistance",
+    CullDistance => "CullDistance",
+    VertexId => "VertexId",
+    InstanceId => "InstanceId",
+    PrimitiveId => "PrimitiveId",
+    InvocationId => "InvocationId",
+    Layer => "Layer",
+    ViewportIndex => "ViewportIndex",
+    TessLevelOuter => "TessLevelOuter",
+    TessLevelInner => "TessLevelInner",
+    TessCoord => "TessCoord",
+    PatchVertices => "PatchVertices",
+    FragCoord => "FragCoord",
+    PointCoord => "PointCoord",
+    FrontFacing => "FrontFacing",
+    SampleId => "SampleId",
+    SamplePosition => "SamplePosition",
+    SampleMask => "SampleMask",
+    FragDepth => "FragDepth",
+    HelperInvocation => "HelperInvocation",
+    NumWorkgroups => "NumWorkgroups",
+    WorkgroupSize => "WorkgroupSize",
+    WorkgroupId => "WorkgroupId",
+    LocalInvocationId => "LocalInvocationId",
+    GlobalInvocationId => "GlobalInvocationId",
+    LocalInvocationIndex => "LocalInvocationIndex",
+    WorkDim => "WorkDim",
+    GlobalSize => "GlobalSize",
+    EnqueuedWorkgroupSize => "EnqueuedWorkgroupSize",
+    GlobalOffset => "GlobalOffset",
+    GlobalLinearId => "GlobalLinearId",
+    SubgroupSize => "SubgroupSize",
+    SubgroupMaxSize => "SubgroupMaxSize",
+    NumSubgroups => "NumSubgroups",
+    NumEnqueuedSubgroups => "NumEnqueuedSubgroups",
+    SubgroupId => "SubgroupId",
+    SubgroupLocalInvocationId => "SubgroupLocalInvocationId",
+    VertexIndex => "VertexIndex",
+    InstanceIndex => "InstanceIndex",
+    SubgroupEqMask => "SubgroupEqMask",
+    SubgroupGeMask => "SubgroupGeMask",
+    SubgroupGtMask => "SubgroupGtMask",
+    SubgroupLeMask => "SubgroupLeMask",
+    SubgroupLtMask => "SubgroupLtMask",
+    BaseVertex => "BaseVertex",
+    BaseInstance => "BaseInstance",
+    DrawIndex => "DrawIndex",
+    PrimitiveShadingRateKhr => "PrimitiveShadingRateKHR",
+    DeviceIndex => "DeviceIndex",
+    ViewIndex => "ViewIndex",
+    ShadingRateKhr => "ShadingRateKHR",
+    BaryCoordNoPerspAmd => "BaryCoordNoPerspAMD",
+    BaryCoordNoPerspCentroidAmd => "BaryCoordNoPerspCentroidAMD",
+    BaryCoordNoPerspSampleAmd => "BaryCoordNoPerspSampleAMD",
+    BaryCoordSmoothAmd => "BaryCoordSmoothAMD",
+    BaryCoordSmoothCentroidAmd => "BaryCoordSmoothCentroidAMD",
+    BaryCoordSmoothSampleAmd => "BaryCoordSmoothSampleAMD",
+    BaryCoordPullModelAmd => "BaryCoordPullModelAMD",
+    FragStencilRefExt => "FragStencilRefEXT",
+    ViewportMaskNv => "ViewportMaskNV",
+    SecondaryPositionNv => "SecondaryPositionNV",
+    SecondaryViewportMaskNv => "SecondaryViewportMaskNV",
+    PositionPerViewNv => "PositionPerViewNV",
+    ViewportMaskPerViewNv => "ViewportMaskPerViewNV",
+    FullyCoveredExt => "FullyCoveredEXT",
+    TaskCountNv => "TaskCountNV",
+    PrimitiveCountNv => "PrimitiveCountNV",
+    PrimitiveIndicesNv => "PrimitiveIndicesNV",
+    ClipDistancePerViewNv => "ClipDistancePerViewNV",
+    CullDistancePerViewNv => "CullDistancePerViewNV",
+    LayerPerViewNv => "LayerPerViewNV",
+    MeshViewCountNv => "MeshViewCountNV",
+    MeshViewIndicesNv => "MeshViewIndicesNV",
+    BaryCoordKhr => "BaryCoordKHR",
+    BaryCoordNoPerspKhr => "BaryCoordNoPerspKHR",
+    FragSizeExt => "FragSizeEXT",
+    FragInvocationCountExt => "FragInvocationCountEXT",
+    PrimitivePointIndicesExt => "PrimitivePointIndicesEXT",
+    PrimitiveLineIndicesExt => "PrimitiveLineIndicesEXT",
+    PrimitiveTriangleIndicesExt => "PrimitiveTriangleIndicesEXT",
+    CullPrimitiveExt => "CullPrimitiveEXT",
+    LaunchIdKhr => "LaunchIdKHR",
+    LaunchSizeKhr => "LaunchSizeKHR",
+    WorldRayOriginKhr => "WorldRayOriginKHR",
+    WorldRayDirectionKhr => "WorldRayDirectionKHR",
+    ObjectRayOriginKhr => "ObjectRayOriginKHR",
+    ObjectRayDirectionKhr => "ObjectRayDirectionKHR",
+    RayTminKhr => "RayTminKHR",
+    RayTmaxKhr => "RayTmaxKHR",
+    InstanceCustomIndexKhr => "InstanceCustomIndexKHR",
+    ObjectToWorldKhr => "ObjectToWorldKHR",
+    WorldToObjectKhr => "WorldToObjectKHR",
+    HitTnv => "HitTNV",
+    HitKindKhr => "HitKindKHR",
+    CurrentRayTimeNv => "CurrentRayTimeNV",
+    IncomingRayFlagsKhr => "IncomingRayFlagsKHR",
+    RayGeometryIndexKhr => "RayGeometryIndexKHR",
+    WarpsPerSmnv => "WarpsPerSMNV",
+    SmCountNv => "SMCountNV",
+    WarpIdnv => "WarpIDNV",
+    Smidnv => "SMIDNV",
+    CullMaskKhr => "CullMaskKHR",
+});
+
+/// The id of a constant, such as one declared with `OpConstant`.
+pub type ConstantId = u32;
+
+/// The raw bits of a scalar constant. Values narrower than 64 bits occupy the low bits.
+pub type ConstantValue = u64;
+
 /// A work group size.
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct WorkGroupSize {
     pub x: u32,
     pub y: u32,
@@ -359,12 +660,205 @@ pub struct WorkGroupSize {
 
 /// An entry point for a SPIR-V module.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EntryPoint {
     pub name: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_execution_model")
+    )]
     pub execution_model: ExecutionModel,
     pub work_group_size: WorkGroupSize,
 }
 
+/// Serializes an execution model by its SPIR-V name, matching its `Display` output.
+#[cfg(feature = "serde")]
+fn serialize_execution_model<S>(
+    execution_model: &ExecutionModel,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_str(execution_model)
+}
+
+/// A shader stage, collapsing the vendor variants of `ExecutionModel` for the common graphics,
+/// compute and ray tracing stages.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ShaderStage {
+    Vertex,
+    TessellationControl,
+    TessellationEvaluation,
+    Geometry,
+    Fragment,
+    Compute,
+    Task,
+    Mesh,
+    RayGeneration,
+    Intersection,
+    AnyHit,
+    ClosestHit,
+    Miss,
+    Callable,
+}
+
+impl std::convert::TryFrom<ExecutionModel> for ShaderStage {
+    type Error = ErrorCode;
+
+    fn try_from(execution_model: ExecutionModel) -> Result<Self, Self::Error> {
+        Ok(match execution_model {
+            ExecutionModel::Vertex => ShaderStage::Vertex,
+            ExecutionModel::TessellationControl => ShaderStage::TessellationControl,
+            ExecutionModel::TessellationEvaluation => ShaderStage::TessellationEvaluation,
+            ExecutionModel::Geometry => ShaderStage::Geometry,
+            ExecutionModel::Fragment => ShaderStage::Fragment,
+            ExecutionModel::GlCompute => ShaderStage::Compute,
+            ExecutionModel::TaskNv | ExecutionModel::TaskExt => ShaderStage::Task,
+            ExecutionModel::MeshNv | ExecutionModel::MeshExt => ShaderStage::Mesh,
+            ExecutionModel::RayGenerationKhr => ShaderStage::RayGeneration,
+            ExecutionModel::IntersectionKhr => ShaderStage::Intersection,
+            ExecutionModel::AnyHitKhr => ShaderStage::AnyHit,
+            ExecutionModel::ClosestHitKhr => ShaderStage::ClosestHit,
+            ExecutionModel::MissKhr => ShaderStage::Miss,
+            ExecutionModel::CallableKhr => ShaderStage::Callable,
+            ExecutionModel::Kernel => {
+                return Err(ErrorCode::CompilationError(String::from(
+                    "kernel execution model has no shader stage",
+                )))
+            }
+        })
+    }
+}
+
+impl From<ShaderStage> for ExecutionModel {
+    fn from(stage: ShaderStage) -> Self {
+        match stage {
+            ShaderStage::Vertex => ExecutionModel::Vertex,
+            ShaderStage::TessellationControl => ExecutionModel::TessellationControl,
+            ShaderStage::TessellationEvaluation => ExecutionModel::TessellationEvaluation,
+            ShaderStage::Geometry => ExecutionModel::Geometry,
+            ShaderStage::Fragment => ExecutionModel::Fragment,
+            ShaderStage::Compute => ExecutionModel::GlCompute,
+            ShaderStage::Task => ExecutionModel::TaskExt,
+            ShaderStage::Mesh => ExecutionModel::MeshExt,
+            ShaderStage::RayGeneration => ExecutionModel::RayGenerationKhr,
+            ShaderStage::Intersection => ExecutionModel::IntersectionKhr,
+            ShaderStage::AnyHit => ExecutionModel::AnyHitKhr,
+            ShaderStage::ClosestHit => ExecutionModel::ClosestHitKhr,
+            ShaderStage::Miss => ExecutionModel::MissKhr,
+            ShaderStage::Callable => ExecutionModel::CallableKhr,
+        }
+    }
+}
+
+/// The primitive type consumed by a geometry shader.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum GeometryInputPrimitive {
+    Points,
+    Lines,
+    LinesAdjacency,
+    Triangles,
+    TrianglesAdjacency,
+}
+
+/// The primitive type emitted by a geometry shader.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum GeometryOutputPrimitive {
+    Points,
+    LineStrip,
+    TriangleStrip,
+}
+
+/// Pipeline state declared by the execution modes of a geometry shader.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GeometryInfo {
+    pub input_primitive: Option<GeometryInputPrimitive>,
+    pub output_primitive: Option<GeometryOutputPrimitive>,
+    /// The maximum number of vertices emitted per invocation.
+    pub output_vertices: u32,
+    /// The number of invocations per input primitive. Defaults to 1.
+    pub invocations: u32,
+}
+
+/// The primitive type generated by the tessellator.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TessellationPrimitive {
+    Triangles,
+    Quads,
+    Isolines,
+}
+
+/// The spacing of the segments generated by the tessellator.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TessellationSpacing {
+    Equal,
+    FractionalEven,
+    FractionalOdd,
+}
+
+/// The winding order of triangles generated by the tessellator.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TessellationWinding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Pipeline state declared by the execution modes of a tessellation shader. Either stage may
+/// declare any of these, so those not declared by the given stage are `None`.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TessellationInfo {
+    pub primitive: Option<TessellationPrimitive>,
+    pub spacing: Option<TessellationSpacing>,
+    pub winding: Option<TessellationWinding>,
+    /// Whether the tessellator generates points rather than primitives.
+    pub point_mode: bool,
+    /// The number of vertices in the output patch.
+    pub output_vertices: Option<u32>,
+}
+
+/// A conservative depth layout, promising how a fragment shader changes depth.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum DepthLayout {
+    /// Depth is only ever increased.
+    Greater,
+    /// Depth is only ever decreased.
+    Less,
+    /// Depth is left unchanged.
+    Unchanged,
+}
+
+/// Depth state declared by the execution modes of a fragment shader.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FragmentDepthMode {
+    /// Whether depth and stencil tests run before the shader executes.
+    pub early_fragment_tests: bool,
+    /// Whether the shader writes depth.
+    pub depth_replacing: bool,
+    pub depth_layout: Option<DepthLayout>,
+}
+
+/// Active interface variables of an entry point, split by storage class.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ActiveIo {
+    /// Variables in the `Input` storage class.
+    pub inputs: HashSet<u32>,
+    /// Variables in the `Output` storage class.
+    pub outputs: HashSet<u32>,
+    /// All other variables, such as uniform buffers and images.
+    pub resources: HashSet<u32>,
+}
+
 /// Description of struct member's range.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct BufferRange {
@@ -376,8 +870,37 @@ pub struct BufferRange {
     pub range: usize,
 }
 
+/// The bytes of the push constant block accessed by a shader, as returned by
+/// `Ast::get_push_constant_range`.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PushConstantRange {
+    /// Bytes from the start of the block to the first accessed member.
+    pub offset: u32,
+    /// Bytes from the start of the first accessed member to the end of the last.
+    pub size: u32,
+    /// The stages accessing the range, initially the stage of the current entry point.
+    pub stages: Vec<ShaderStage>,
+}
+
+/// Layout of a struct member within a buffer block.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct MemberLayout {
+    /// Bytes from the beginning of the struct.
+    pub offset: u32,
+    /// Declared size of the member in bytes.
+    pub size: u32,
+    /// Stride between array elements, or `None` if the member is not an array.
+    pub array_stride: Option<u32>,
+    /// Stride between matrix columns (or rows), or `None` if the member is not a matrix.
+    pub matrix_stride: Option<u32>,
+    /// Whether a matrix member is laid out in row-major order.
+    pub row_major: bool,
+}
+
 /// A resource.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Resource {
     pub id: u32,
     pub type_id: u32,
@@ -387,21 +910,80 @@ pub struct Resource {
 
 /// A built-in resource.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BuiltInResource {
     pub builtin: BuiltIn,
     pub value_type_id: u32,
     pub resource: Resource,
 }
 
+/// Binding information for an atomic counter.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct AtomicCounterInfo {
+    pub binding: u32,
+    pub offset: u32,
+}
+
+/// The kind of object an id refers to, matching the handle types SPIRV-Cross creates while
+/// parsing a module.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum IdType {
+    Type,
+    Variable,
+    /// A constant or specialization constant.
+    Constant,
+    Function,
+    FunctionPrototype,
+    Block,
+    /// An extended instruction set import.
+    Extension,
+    /// A specialization constant operation.
+    ConstantOp,
+    Undef,
+    String,
+}
+
+#[cfg(feature = "backend")]
+impl IdType {
+    /// Gets the value of the corresponding `spirv_cross::Types` enumerator.
+    pub(crate) fn as_raw(self) -> u32 {
+        match self {
+            IdType::Type => 1,
+            IdType::Variable => 2,
+            IdType::Constant => 3,
+            IdType::Function => 4,
+            IdType::FunctionPrototype => 5,
+            IdType::Block => 6,
+            IdType::Extension => 7,
+            IdType::ConstantOp => 9,
+            IdType::Undef => 12,
+            IdType::String => 13,
+        }
+    }
+}
+
 /// Specialization constant reference.
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SpecializationConstant {
     pub id: u32,
     pub constant_id: u32,
 }
 
+/// Where a specialization constant is used, as returned by
+/// `Ast::get_specialization_constant_usages`.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SpecializationConstantUsage {
+    /// The length of the outermost dimension of the array type with this id.
+    ArraySize(u32),
+    /// The work group size in dimension 0, 1 or 2, for x, y and z.
+    WorkGroupSize(u32),
+}
+
 /// Work group size specialization constants.
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct WorkGroupSizeSpecializationConstants {
     pub x: SpecializationConstant,
     pub y: SpecializationConstant,
@@ -410,6 +992,7 @@ pub struct WorkGroupSizeSpecializationConstants {
 
 /// Shader resources.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ShaderResources {
     pub uniform_buffers: Vec<Resource>,
     pub storage_buffers: Vec<Resource>,
@@ -428,12 +1011,231 @@ pub struct ShaderResources {
     pub builtin_outputs: Vec<BuiltInResource>,
 }
 
+#[cfg(feature = "backend")]
+impl ShaderResources {
+    /// Gets the descriptor resources with their descriptor set and binding, ordered by
+    /// `(set, binding)`. Resources without an explicit binding have a binding of `None` and
+    /// are placed last, in their original order. Stage IO, push constant and shader record
+    /// buffers are not included.
+    pub fn sorted_by_binding<TTarget>(
+        &self,
+        ast: &Ast<TTarget>,
+    ) -> Result<Vec<(Resource, u32, Option<u32>)>, ErrorCode>
+    where
+        TTarget: Target,
+    {
+        let resources = self
+            .uniform_buffers
+            .iter()
+            .chain(&self.storage_buffers)
+            .chain(&self.subpass_inputs)
+            .chain(&self.storage_images)
+            .chain(&self.sampled_images)
+            .chain(&self.atomic_counters)
+            .chain(&self.acceleration_structures)
+            .chain(&self.separate_images)
+            .chain(&self.separate_samplers);
+
+        let mut bindings = Vec::new();
+        for resource in resources {
+            let set = ast
+                .compiler
+                .get_decoration(resource.id, Decoration::DescriptorSet)?;
+            let binding = if ast
+                .compiler
+                .has_decoration(resource.id, Decoration::Binding)?
+            {
+                Some(
+                    ast.compiler
+                        .get_decoration(resource.id, Decoration::Binding)?,
+                )
+            } else {
+                None
+            };
+            bindings.push((resource.clone(), set, binding));
+        }
+        // The sort is stable, so unbound resources keep their original order.
+        bindings.sort_by_key(|&(_, set, binding)| match binding {
+            Some(binding) => (false, set, binding),
+            None => (true, 0, 0),
+        });
+
+        Ok(bindings)
+    }
+}
+
+/// The kind of a shader resource, matching the `ShaderResources` field it is listed in.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ResourceKind {
+    UniformBuffer,
+    StorageBuffer,
+    StageInput,
+    StageOutput,
+    SubpassInput,
+    StorageImage,
+    SampledImage,
+    AtomicCounter,
+    AccelerationStructure,
+    PushConstantBuffer,
+    ShaderRecordBuffer,
+    SeparateImage,
+    SeparateSampler,
+}
+
+/// A shader resource with its binding decorations, as returned by
+/// `Ast::resources_with_bindings`. Decorations the resource doesn't have are `None`.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ResourceBindingInfo {
+    pub resource: Resource,
+    pub kind: ResourceKind,
+    pub set: Option<u32>,
+    pub binding: Option<u32>,
+    pub location: Option<u32>,
+}
+
+/// Where a variable binds, as returned by `Ast::get_binding`. Decorations the variable
+/// doesn't have are `None`.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Binding {
+    pub set: Option<u32>,
+    pub binding: Option<u32>,
+    pub location: Option<u32>,
+    pub input_attachment_index: Option<u32>,
+}
+
+/// The number of descriptors of each type in a descriptor set, as returned by
+/// `Ast::descriptor_set_summary`.
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DescriptorCounts {
+    pub uniform_buffers: u32,
+    pub storage_buffers: u32,
+    pub subpass_inputs: u32,
+    pub storage_images: u32,
+    pub sampled_images: u32,
+    pub atomic_counters: u32,
+    pub acceleration_structures: u32,
+    pub separate_images: u32,
+    pub separate_samplers: u32,
+    /// Sampled and separate images with `Dim::Buffer`, which are counted here rather than in
+    /// `sampled_images` or `separate_images`.
+    pub uniform_texel_buffers: u32,
+    /// Storage images with `Dim::Buffer`, which are counted here rather than in
+    /// `storage_images`.
+    pub storage_texel_buffers: u32,
+}
+
+#[cfg(feature = "backend")]
+type DescriptorCountField = fn(&mut DescriptorCounts) -> &mut u32;
+
+/// Clip space conventions of a vertex-like shader, shared by all targets. When set on a
+/// target's compiler options, these replace its `vertex.invert_y` and
+/// `vertex.transform_clip_space` options:
+///
+/// - `invert_y` is set to `flip_y` on every target.
+/// - GLSL sets `transform_clip_space` if `zero_to_one_depth` is set, remapping depth to the
+///   `[-w, w]` range used by OpenGL.
+/// - HLSL and MSL set `transform_clip_space` if `zero_to_one_depth` is not set, remapping
+///   depth from `[-w, w]` to the `[0, w]` range used by Direct3D and Metal.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ClipSpaceOptions {
+    /// Whether to negate the Y coordinate of the output position.
+    pub flip_y: bool,
+    /// Whether the shader outputs depth in the `[0, w]` range, as in Vulkan, rather than the
+    /// `[-w, w]` range of OpenGL.
+    pub zero_to_one_depth: bool,
+}
+
+impl Default for ClipSpaceOptions {
+    fn default() -> Self {
+        ClipSpaceOptions {
+            flip_y: false,
+            zero_to_one_depth: true,
+        }
+    }
+}
+
+/// Options shared by every target. SPIRV-Cross keeps these on the GLSL compiler, which the
+/// HLSL and MSL compilers derive from.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct CommonOptions {
+    /// Whether to flatten arrays of arrays into one-dimensional arrays.
+    pub flatten_multidimensional_arrays: bool,
+    /// Whether to load row-major matrices from buffers through a helper function, working
+    /// around drivers that mishandle them.
+    pub enable_row_major_load_workaround: bool,
+    /// The number of views to declare with `GL_OVR_multiview2`, or 0 to not use the extension.
+    pub ovr_multiview_view_count: u32,
+}
+
+impl Default for CommonOptions {
+    fn default() -> Self {
+        CommonOptions {
+            flatten_multidimensional_arrays: false,
+            enable_row_major_load_workaround: true,
+            ovr_multiview_view_count: 0,
+        }
+    }
+}
+
+/// Reflection of a module, as returned by `Ast::reflect`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Reflection {
+    pub entry_points: Vec<EntryPoint>,
+    pub shader_resources: ShaderResources,
+    pub specialization_constants: Vec<SpecializationConstant>,
+    pub work_group_size_specialization_constants: WorkGroupSizeSpecializationConstants,
+}
+
 pub use crate::bindings::spv::Dim;
 pub use crate::bindings::spv::ImageFormat;
 pub use crate::bindings::spirv_cross::SPIRType_BaseType;
+#[cfg(feature = "backend")]
 use crate::bindings::spv;
 
-#[derive(Debug, Clone)]
+/// How the components of an image format are stored and read.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum FormatNumericType {
+    Float,
+    Unorm,
+    Snorm,
+    Sint,
+    Uint,
+}
+
+impl ImageFormat {
+    /// Gets the number of components and their numeric type, or `None` for `Unknown`.
+    pub fn components_and_type(self) -> Option<(u8, FormatNumericType)> {
+        use FormatNumericType::*;
+        use ImageFormat as F;
+        Some(match self {
+            F::Unknown => return None,
+            F::Rgba32f | F::Rgba16f => (4, Float),
+            F::Rg32f | F::Rg16f => (2, Float),
+            F::R32f | F::R16f => (1, Float),
+            F::R11fG11fB10f => (3, Float),
+            F::Rgba16 | F::Rgba8 | F::Rgb10A2 => (4, Unorm),
+            F::Rg16 | F::Rg8 => (2, Unorm),
+            F::R16 | F::R8 => (1, Unorm),
+            F::Rgba16Snorm | F::Rgba8Snorm => (4, Snorm),
+            F::Rg16Snorm | F::Rg8Snorm => (2, Snorm),
+            F::R16Snorm | F::R8Snorm => (1, Snorm),
+            F::Rgba32i | F::Rgba16i | F::Rgba8i => (4, Sint),
+            F::Rg32i | F::Rg16i | F::Rg8i => (2, Sint),
+            F::R64i | F::R32i | F::R16i | F::R8i => (1, Sint),
+            F::Rgba32ui | F::Rgba16ui | F::Rgba8ui | F::Rgb10A2ui => (4, Uint),
+            F::Rg32ui | F::Rg16ui | F::Rg8ui => (2, Uint),
+            F::R64ui | F::R32ui | F::R16ui | F::R8ui => (1, Uint),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ImageType {
     pub type_id: u32,
     pub dim: Dim,
@@ -444,7 +1246,7 @@ pub struct ImageType {
     pub format: ImageFormat,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
 pub enum Type {
     // TODO: Add missing fields to relevant variants from SPIRType
@@ -461,23 +1263,27 @@ pub enum Type {
         array_size_literal: Vec<bool>,
     },
     Int {
+        width: u32,
         vecsize: u32,
         columns: u32,
         array: Vec<u32>,
         array_size_literal: Vec<bool>,
     },
     UInt {
+        width: u32,
         vecsize: u32,
         columns: u32,
         array: Vec<u32>,
         array_size_literal: Vec<bool>,
     },
     Int64 {
+        width: u32,
         vecsize: u32,
         array: Vec<u32>,
         array_size_literal: Vec<bool>,
     },
     UInt64 {
+        width: u32,
         vecsize: u32,
         array: Vec<u32>,
         array_size_literal: Vec<bool>,
@@ -487,18 +1293,21 @@ pub enum Type {
         array_size_literal: Vec<bool>,
     },
     Half {
+        width: u32,
         vecsize: u32,
         columns: u32,
         array: Vec<u32>,
         array_size_literal: Vec<bool>,
     },
     Float {
+        width: u32,
         vecsize: u32,
         columns: u32,
         array: Vec<u32>,
         array_size_literal: Vec<bool>,
     },
     Double {
+        width: u32,
         vecsize: u32,
         columns: u32,
         array: Vec<u32>,
@@ -518,54 +1327,399 @@ pub enum Type {
         array: Vec<u32>,
         array_size_literal: Vec<bool>,
         image: ImageType,
+        /// Whether the sampler is a comparison sampler, as used for shadow maps. Taken from
+        /// the depth flag of the image.
+        comparison: bool,
     },
     Sampler {
         array: Vec<u32>,
         array_size_literal: Vec<bool>,
     },
     SByte {
+        width: u32,
         vecsize: u32,
         array: Vec<u32>,
         array_size_literal: Vec<bool>,
     },
     UByte {
+        width: u32,
         vecsize: u32,
         array: Vec<u32>,
         array_size_literal: Vec<bool>,
     },
     Short {
+        width: u32,
         vecsize: u32,
         array: Vec<u32>,
         array_size_literal: Vec<bool>,
     },
     UShort {
+        width: u32,
         vecsize: u32,
         array: Vec<u32>,
         array_size_literal: Vec<bool>,
     },
-    ControlPointArray,
-    AccelerationStructure,
-    RayQuery,
-    Interpolant,
+    /// An array of tessellation control points, as laid out by the MSL backend.
+    ControlPointArray {
+        element_type: u32,
+        array: Vec<u32>,
+        array_size_literal: Vec<bool>,
+    },
+    AccelerationStructure {
+        array: Vec<u32>,
+        array_size_literal: Vec<bool>,
+    },
+    RayQuery {
+        array: Vec<u32>,
+        array_size_literal: Vec<bool>,
+    },
+    /// A pull-model interpolant, as emitted by the MSL backend.
+    Interpolant {
+        element_type: u32,
+        array: Vec<u32>,
+        array_size_literal: Vec<bool>,
+    },
+    /// A physical pointer, such as a buffer device address. Pointers to variables in logical
+    /// storage classes report the type they point to instead.
+    Pointer {
+        pointee_type: u32,
+        storage_class: StorageClass,
+        array: Vec<u32>,
+        array_size_literal: Vec<bool>,
+    },
+}
+
+/// A packing rule for the members of a buffer block.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum LayoutRule {
+    /// Array and matrix column strides are rounded up to 16 bytes.
+    Std140,
+    Std430,
+}
+
+impl Type {
+    /// Gets the size in bytes of the type under `rule`, including array padding. Matrices
+    /// are assumed to be column-major.
+    ///
+    /// Returns `None` for opaque types such as images and samplers, for structs, whose
+    /// member types must be resolved through the `Ast`, and for arrays whose size is a
+    /// specialization constant or is not known until runtime.
+    pub fn size(&self, rule: LayoutRule) -> Option<usize> {
+        let (scalar_size, vecsize, columns, array, array_size_literal) = match self {
+            Type::Boolean {
+                vecsize,
+                columns,
+                array,
+                array_size_literal,
+            } => (4, *vecsize, *columns, array, array_size_literal),
+            Type::Int {
+                width,
+                vecsize,
+                columns,
+                array,
+                array_size_literal,
+            }
+            | Type::UInt {
+                width,
+                vecsize,
+                columns,
+                array,
+                array_size_literal,
+            }
+            | Type::Half {
+                width,
+                vecsize,
+                columns,
+                array,
+                array_size_literal,
+            }
+            | Type::Float {
+                width,
+                vecsize,
+                columns,
+                array,
+                array_size_literal,
+            }
+            | Type::Double {
+                width,
+                vecsize,
+                columns,
+                array,
+                array_size_literal,
+            } => (
+                *width as usize / 8,
+                *vecsize,
+                *columns,
+                array,
+                array_size_literal,
+            ),
+            Type::Int64 {
+                width,
+                vecsize,
+                array,
+                array_size_literal,
+            }
+            | Type::UInt64 {
+                width,
+                vecsize,
+                array,
+                array_size_literal,
+            }
+            | Type::Short {
+                width,
+                vecsize,
+                array,
+                array_size_literal,
+            }
+            | Type::UShort {
+                width,
+                vecsize,
+                array,
+                array_size_literal,
+            }
+            | Type::SByte {
+                width,
+                vecsize,
+                array,
+                array_size_literal,
+            }
+            | Type::UByte {
+                width,
+                vecsize,
+                array,
+                array_size_literal,
+            } => (*width as usize / 8, *vecsize, 1, array, array_size_literal),
+            Type::Char {
+                array,
+                array_size_literal,
+            } => (1, 1, 1, array, array_size_literal),
+            Type::Pointer {
+                array,
+                array_size_literal,
+                ..
+            } => (8, 1, 1, array, array_size_literal),
+            _ => return None,
+        };
+
+        let vector_alignment = match vecsize {
+            1 | 2 => vecsize as usize * scalar_size,
+            _ => 4 * scalar_size,
+        };
+        let (mut size, mut alignment) = if columns > 1 {
+            let column_stride = match rule {
+                LayoutRule::Std140 => vector_alignment.next_multiple_of(16),
+                LayoutRule::Std430 => vector_alignment,
+            };
+            (columns as usize * column_stride, column_stride)
+        } else {
+            (vecsize as usize * scalar_size, vector_alignment)
+        };
+
+        if !array.is_empty() {
+            if rule == LayoutRule::Std140 {
+                alignment = alignment.next_multiple_of(16);
+            }
+            let mut element_count = 1;
+            for (&length, &is_literal) in array.iter().zip(array_size_literal) {
+                if !is_literal || length == 0 {
+                    return None;
+                }
+                element_count *= length as usize;
+            }
+            size = element_count * size.next_multiple_of(alignment);
+        }
+
+        Some(size)
+    }
 }
 
+#[cfg(feature = "backend")]
+/// The first word of a SPIR-V module in the host's byte order.
+const MAGIC_NUMBER: u32 = 0x0723_0203;
+
+#[cfg(feature = "backend")]
+/// The number of words in the header of a SPIR-V module, before the first instruction.
+pub(crate) const HEADER_WORD_COUNT: usize = 5;
+
+#[cfg(feature = "backend")]
 /// A SPIR-V shader module.
 #[derive(Debug, Clone)]
 pub struct Module<'a> {
     pub(crate) words: &'a [u32],
 }
 
+#[cfg(feature = "backend")]
+/// Limits checked against a SPIR-V module before it is handed to the parser.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct ParseLimits {
+    /// The maximum id bound declared in the module header.
+    pub max_id_bound: u32,
+    /// The maximum number of words in the module, including the header.
+    pub max_word_count: usize,
+}
+
+#[cfg(feature = "backend")]
+impl Default for ParseLimits {
+    fn default() -> Self {
+        ParseLimits {
+            max_id_bound: u32::MAX,
+            max_word_count: usize::MAX,
+        }
+    }
+}
+
+#[cfg(feature = "backend")]
 impl<'a> Module<'a> {
     /// Creates a shader module from SPIR-V words.
     pub fn from_words(words: &[u32]) -> Module {
         Module { words }
     }
+
+    /// Checks the module header before it is handed to SPIRV-Cross, which aborts rather than
+    /// erroring on malformed input in native builds.
+    pub(crate) fn validate_header(&self) -> Result<(), ErrorCode> {
+        if self.words.len() < HEADER_WORD_COUNT {
+            return Err(ErrorCode::CompilationError(String::from(
+                "module is too small to contain a SPIR-V header",
+            )));
+        }
+
+        let magic_number = self.words[0];
+        if magic_number != MAGIC_NUMBER && magic_number.swap_bytes() != MAGIC_NUMBER {
+            return Err(ErrorCode::CompilationError(format!(
+                "invalid SPIR-V magic number {:#010x}",
+                magic_number
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Creates a shader module from SPIR-V words, rejecting modules that exceed `limits`.
+    pub fn from_words_limited(words: &[u32], limits: ParseLimits) -> Result<Module<'_>, ErrorCode> {
+        if words.len() > limits.max_word_count {
+            return Err(ErrorCode::CompilationError(format!(
+                "module word count {} exceeds `max_word_count` of {}",
+                words.len(),
+                limits.max_word_count
+            )));
+        }
+
+        if let Some(&id_bound) = words.get(3) {
+            if id_bound > limits.max_id_bound {
+                return Err(ErrorCode::CompilationError(format!(
+                    "module id bound {} exceeds `max_id_bound` of {}",
+                    id_bound, limits.max_id_bound
+                )));
+            }
+        }
+
+        Ok(Module { words })
+    }
+
+    /// Runs the SPIRV-Cross parser over the module without constructing a compiler, which is
+    /// cheaper than `Ast::parse`. Returns an error if the module cannot be parsed, and
+    /// otherwise any problems that would prevent it from being compiled usefully.
+    pub fn validate(&self) -> Result<Vec<ValidationWarning>, ErrorCode> {
+        self.validate_header()?;
+        let (has_entry_points, addressing_model) = compiler::parse_module(self)?;
+
+        let mut warnings = Vec::new();
+        if !has_entry_points {
+            warnings.push(ValidationWarning::NoEntryPoints);
+        }
+        if addressing_model != spv::AddressingModel::Logical
+            && addressing_model != spv::AddressingModel::PhysicalStorageBuffer64
+        {
+            warnings.push(ValidationWarning::PhysicalAddressingModel);
+        }
+
+        Ok(warnings)
+    }
+}
+
+#[cfg(feature = "backend")]
+/// A problem found by `Module::validate` that does not prevent the module from being parsed.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ValidationWarning {
+    /// The module declares no entry points, so it can be reflected but not compiled.
+    NoEntryPoints,
+    /// The module uses the `Physical32` or `Physical64` addressing model of OpenCL kernels,
+    /// which cannot be cross-compiled to a shading language.
+    PhysicalAddressingModel,
+}
+
+#[cfg(feature = "backend")]
+/// A SPIR-V shader module that owns its words.
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct OwnedModule {
+    words: Vec<u32>,
+}
+
+#[cfg(feature = "backend")]
+impl OwnedModule {
+    /// Creates a shader module from SPIR-V words.
+    pub fn from_words(words: Vec<u32>) -> OwnedModule {
+        OwnedModule { words }
+    }
+
+    /// Creates a shader module from the bytes of a SPIR-V binary, in either byte order.
+    pub fn from_bytes(bytes: &[u8]) -> Result<OwnedModule, ErrorCode> {
+        let chunks = bytes.chunks_exact(4);
+        if !chunks.remainder().is_empty() {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`bytes` length must be a multiple of 4",
+            )));
+        }
+
+        let mut words = chunks
+            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect::<Vec<_>>();
+        if words.first().map(|word| word.swap_bytes()) == Some(MAGIC_NUMBER) {
+            for word in &mut words {
+                *word = word.swap_bytes();
+            }
+        }
+
+        Ok(OwnedModule { words })
+    }
+
+    /// Borrows the words as a `Module`, e.g. for `Ast::parse`.
+    pub fn as_module(&self) -> Module<'_> {
+        Module { words: &self.words }
+    }
+
+    /// Gets the SPIR-V words.
+    pub fn words(&self) -> &[u32] {
+        &self.words
+    }
 }
 
+#[cfg(feature = "backend")]
+impl<'a> From<&'a OwnedModule> for Module<'a> {
+    fn from(module: &'a OwnedModule) -> Self {
+        module.as_module()
+    }
+}
+
+#[cfg(feature = "backend")]
 pub trait Target {
     type Data;
+
+    /// Invalidates any target data derived from the active entry point.
+    #[doc(hidden)]
+    fn reset_entry_point_data(_data: &mut Self::Data) {}
+
+    /// Returns whether `name` is a keyword, type or function name of the target language,
+    /// which SPIRV-Cross renames when it is used as an identifier.
+    #[doc(hidden)]
+    fn is_reserved_name(_name: &str) -> bool {
+        false
+    }
 }
 
+#[cfg(feature = "backend")]
 /// An abstract syntax tree that corresponds to a SPIR-V module.
 pub struct Ast<TTarget>
     where
@@ -575,10 +1729,12 @@ pub struct Ast<TTarget>
     pub(crate) target_type: PhantomData<TTarget>,
 }
 
+#[cfg(feature = "backend")]
 pub trait Parse<TTarget>: Sized {
     fn parse(module: &Module) -> Result<Self, ErrorCode>;
 }
 
+#[cfg(feature = "backend")]
 pub trait Compile<TTarget> {
     type CompilerOptions;
 
@@ -587,8 +1743,15 @@ pub trait Compile<TTarget> {
         compiler_options: &Self::CompilerOptions,
     ) -> Result<(), ErrorCode>;
     fn compile(&mut self) -> Result<String, ErrorCode>;
+
+    /// Compiles to raw bytes. Targets that produce binary output should override this so
+    /// their output is not required to be valid UTF-8.
+    fn compile_bytes(&mut self) -> Result<Vec<u8>, ErrorCode> {
+        self.compile().map(String::into_bytes)
+    }
 }
 
+#[cfg(feature = "backend")]
 impl<TTarget> Ast<TTarget>
     where
         Self: Parse<TTarget> + Compile<TTarget>,
@@ -599,23 +1762,119 @@ impl<TTarget> Ast<TTarget>
         self.compiler.get_decoration(id, decoration)
     }
 
+    /// Gets the offset in words of the literal argument of `decoration` on `id` within the
+    /// module, so that it can be patched without recompiling. Returns `None` if `id` has no
+    /// such decoration or SPIRV-Cross does not track its offset, which it only does for
+    /// decorations such as `Binding`, `DescriptorSet` and `Location`.
+    pub fn get_binary_offset_for_decoration(
+        &self,
+        id: u32,
+        decoration: Decoration,
+    ) -> Result<Option<u32>, ErrorCode> {
+        self.compiler.get_binary_offset_for_decoration(id, decoration)
+    }
+
+    /// Gets every id decorated with `decoration` along with the offset in words of the
+    /// decoration's literal argument, as for `get_binary_offset_for_decoration`. Ids whose
+    /// offset is not tracked are skipped.
+    pub fn get_binary_offsets(&self, decoration: Decoration) -> Result<Vec<(u32, u32)>, ErrorCode> {
+        let mut offsets = Vec::new();
+        for id in 1..self.compiler.get_id_bound()? {
+            if !self.compiler.has_decoration(id, decoration)? {
+                continue;
+            }
+            if let Some(offset) = self
+                .compiler
+                .get_binary_offset_for_decoration(id, decoration)?
+            {
+                offsets.push((id, offset));
+            }
+        }
+
+        Ok(offsets)
+    }
+
     /// Gets a name. If not defined, an empty string will be returned.
     pub fn get_name(&mut self, id: u32) -> Result<String, ErrorCode> {
         self.compiler.get_name(id)
     }
 
+    /// Gets a name like `get_name`, without allocating if it is not defined.
+    pub fn get_name_cow(&self, id: u32) -> Result<Cow<'static, str>, ErrorCode> {
+        self.compiler.get_name_cow(id)
+    }
+
     /// Sets a name.
     pub fn set_name(&mut self, id: u32, name: &str) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
         self.compiler.set_name(id, name)
     }
 
     /// Sets a member name.
     pub fn set_member_name(&mut self, id: u32, index: u32, name: &str) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        self.compiler.set_member_name(id, index, name)
+    }
+
+    /// Sets a member name, failing with `ErrorCode::InvalidArgument` if `name` is not a valid
+    /// identifier, is reserved by the target language or is already used by another member of
+    /// the struct. SPIRV-Cross would rename the member in the first two cases, and the last
+    /// would produce uncompilable output.
+    pub fn set_member_name_checked(
+        &mut self,
+        id: u32,
+        index: u32,
+        name: &str,
+    ) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        if !is_valid_identifier(name) {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "`{}` is not a valid identifier",
+                name
+            )));
+        }
+        if TTarget::is_reserved_name(name) {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "`{}` is a reserved name",
+                name
+            )));
+        }
+
+        let member_count = match self.compiler.get_type(id)? {
+            Type::Struct { member_types, .. } => member_types.len() as u32,
+            _ => {
+                return Err(ErrorCode::InvalidArgument(String::from(
+                    "`id` must be a struct type",
+                )))
+            }
+        };
+        if index >= member_count {
+            return Err(ErrorCode::InvalidArgument(String::from(
+                "`index` is out of bounds",
+            )));
+        }
+        for other_index in (0..member_count).filter(|&other_index| other_index != index) {
+            if self.compiler.get_member_name(id, other_index)? == name {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "`{}` is already used by member {}",
+                    name, other_index
+                )));
+            }
+        }
+
         self.compiler.set_member_name(id, index, name)
     }
 
+    /// Clears all names and member names so that generated identifiers are
+    /// emitted instead of the original ones.
+    pub fn strip_debug_info(&mut self) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        self.compiler.strip_debug_info()
+    }
+
     /// Unsets a decoration.
     pub fn unset_decoration(&mut self, id: u32, decoration: Decoration) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
         self.compiler.unset_decoration(id, decoration)
     }
 
@@ -626,62 +1885,788 @@ impl<TTarget> Ast<TTarget>
         decoration: Decoration,
         argument: u32,
     ) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
         self.compiler.set_decoration(id, decoration, argument)
     }
 
-    /// Gets entry points.
-    pub fn get_entry_points(&self) -> Result<Vec<EntryPoint>, ErrorCode> {
-        self.compiler.get_entry_points()
-    }
-
-    /// Gets cleansed entry point names. `compile` must be called first.
-    pub fn get_cleansed_entry_point_name(
-        &self,
-        entry_point_name: &str,
-        execution_model: ExecutionModel,
-    ) -> Result<String, ErrorCode> {
-        if self.compiler.has_been_compiled {
+    /// Sets each `(id, decoration, argument)` in `decorations`, in order.
+    ///
+    /// Stops at the first entry that cannot be applied, such as one whose `id` is not less
+    /// than the id bound, and returns its index in `decorations` along with the error. Entries
+    /// before it remain applied and entries after it are not applied.
+    pub fn apply_decorations(
+        &mut self,
+        decorations: &[(u32, Decoration, u32)],
+    ) -> Result<(), (usize, ErrorCode)> {
+        self.clear_compile_cache();
+        let id_bound = self.compiler.get_id_bound().map_err(|error| (0, error))?;
+        for (index, &(id, decoration, argument)) in decorations.iter().enumerate() {
+            if id >= id_bound {
+                return Err((
+                    index,
+                    ErrorCode::InvalidArgument(format!("id {} is out of bounds", id)),
+                ));
+            }
             self.compiler
-                .get_cleansed_entry_point_name(entry_point_name, execution_model)
-        } else {
-            Err(ErrorCode::CompilationError(String::from(
-                "`compile` must be called first",
-            )))
+                .set_decoration(id, decoration, argument)
+                .map_err(|error| (index, error))?;
         }
+
+        Ok(())
     }
 
-    /// Gets active buffer ragnes.  Useful for push constants.
-    pub fn get_active_buffer_ranges(&self, id: u32) -> Result<Vec<BufferRange>, ErrorCode> {
-        self.compiler.get_active_buffer_ranges(id)
+    /// Sets the `BuiltIn` decoration.
+    pub fn set_builtin(&mut self, id: u32, built_in: BuiltIn) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        let built_in = built_in_as_raw(Some(built_in)) as u32;
+        self.compiler
+            .set_decoration(id, Decoration::BuiltIn, built_in)
     }
 
-    /// Gets all specialization constants.
+    /// Sets the `Binding` decoration.
+    pub fn set_binding(&mut self, id: u32, binding: u32) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        self.compiler
+            .set_decoration(id, Decoration::Binding, binding)
+    }
+
+    /// Sets the `DescriptorSet` decoration.
+    pub fn set_descriptor_set(&mut self, id: u32, descriptor_set: u32) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        self.compiler
+            .set_decoration(id, Decoration::DescriptorSet, descriptor_set)
+    }
+
+    /// Sets the `Location` decoration.
+    pub fn set_location(&mut self, id: u32, location: u32) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        self.compiler
+            .set_decoration(id, Decoration::Location, location)
+    }
+
+    /// Gets a string decoration, such as `HlslSemanticGoogle`. If not defined, an empty
+    /// string will be returned.
+    pub fn get_decoration_string(
+        &self,
+        id: u32,
+        decoration: Decoration,
+    ) -> Result<String, ErrorCode> {
+        self.compiler.get_decoration_string(id, decoration)
+    }
+
+    /// Gets a string decoration of a struct member, such as `HlslSemanticGoogle`. If not
+    /// defined, an empty string will be returned.
+    pub fn get_member_decoration_string(
+        &self,
+        id: u32,
+        index: u32,
+        decoration: Decoration,
+    ) -> Result<String, ErrorCode> {
+        self.compiler
+            .get_member_decoration_string(id, index, decoration)
+    }
+
+    /// Sets a string decoration, such as `HlslSemanticGoogle` or `UserTypeGoogle`.
+    pub fn set_decoration_string(
+        &mut self,
+        id: u32,
+        decoration: Decoration,
+        value: &str,
+    ) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        self.compiler.set_decoration_string(id, decoration, value)
+    }
+
+    /// Gets entry points.
+    pub fn get_entry_points(&self) -> Result<Vec<EntryPoint>, ErrorCode> {
+        self.compiler.get_entry_points()
+    }
+
+    /// Selects the entry point that reflection and compilation operate on. Until an entry
+    /// point is selected, the first one declared in the module is used.
+    pub fn set_entry_point(
+        &mut self,
+        name: &str,
+        execution_model: ExecutionModel,
+    ) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        self.select_entry_point(name, execution_model)
+    }
+
+    /// Selects the entry point without freeing the output cached by `compile_cached`, for
+    /// callers which either restore the selection or key the cache on it.
+    pub(crate) fn select_entry_point(
+        &mut self,
+        name: &str,
+        execution_model: ExecutionModel,
+    ) -> Result<(), ErrorCode> {
+        self.compiler.set_entry_point(name, execution_model)?;
+        TTarget::reset_entry_point_data(&mut self.compiler.target_data);
+        Ok(())
+    }
+
+    /// Gets cleansed entry point names. `compile` must be called first.
+    pub fn get_cleansed_entry_point_name(
+        &self,
+        entry_point_name: &str,
+        execution_model: ExecutionModel,
+    ) -> Result<String, ErrorCode> {
+        if self.compiler.has_been_compiled {
+            self.compiler
+                .get_cleansed_entry_point_name(entry_point_name, execution_model)
+        } else {
+            Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )))
+        }
+    }
+
+    /// Gets active buffer ragnes.  Useful for push constants.
+    pub fn get_active_buffer_ranges(&self, id: u32) -> Result<Vec<BufferRange>, ErrorCode> {
+        self.compiler.get_active_buffer_ranges(id)
+    }
+
+    /// Gets all specialization constants.
     pub fn get_specialization_constants(&self) -> Result<Vec<SpecializationConstant>, ErrorCode> {
         self.compiler.get_specialization_constants()
     }
 
+    /// Gets the ids of all constants which are not specialization constants, such as those
+    /// declared with `OpConstant` and `OpConstantComposite`.
+    pub fn get_constants(&self) -> Result<Vec<ConstantId>, ErrorCode> {
+        self.compiler.get_constants()
+    }
+
+    /// Gets the ids of all objects of the given kind, such as every `OpVariable` for
+    /// `IdType::Variable`.
+    pub fn ids_for_type(&self, kind: IdType) -> Result<Vec<u32>, ErrorCode> {
+        self.compiler.get_ids_for_type(kind)
+    }
+
+    /// Gets the raw bits of a scalar constant, as set by `set_scalar_constant`. Values narrower
+    /// than 64 bits occupy the low bits. Composite constants are not supported.
+    pub fn get_constant_value(&self, id: ConstantId) -> Result<u64, ErrorCode> {
+        self.compiler.get_scalar_constant(id)
+    }
+
     /// Set reference of a scalar constant to a value, overriding the default.
     ///
     /// Can be used to override specialization constants.
     pub fn set_scalar_constant(&mut self, id: u32, value: u64) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
         self.compiler.set_scalar_constant(id, value)
     }
 
+    /// Sets each component of a composite constant, such as a vector specialization constant
+    /// used for the work group size. Components of nested composites are given in order, and
+    /// each value is interpreted as in `set_scalar_constant`.
+    pub fn set_composite_constant(
+        &mut self,
+        id: ConstantId,
+        values: &[ConstantValue],
+    ) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        self.compiler.set_composite_constant(id, values)
+    }
+
     /// Gets shader resources.
     pub fn get_shader_resources(&self) -> Result<ShaderResources, ErrorCode> {
         self.compiler.get_shader_resources()
     }
 
-    /// Gets the SPIR-V type associated with an ID.
+    /// Gets the binding and offset of an atomic counter resource.
+    pub fn get_atomic_counter_info(&self, id: u32) -> Result<AtomicCounterInfo, ErrorCode> {
+        let atomic_counters = self.compiler.get_shader_resources()?.atomic_counters;
+        if !atomic_counters.iter().any(|resource| resource.id == id) {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`id` must be an atomic counter resource",
+            )));
+        }
+
+        Ok(AtomicCounterInfo {
+            binding: self.compiler.get_decoration(id, Decoration::Binding)?,
+            offset: self.compiler.get_decoration(id, Decoration::Offset)?,
+        })
+    }
+
+    /// Gets the `InputAttachmentIndex` decoration of a subpass input, such as a resource in
+    /// `ShaderResources::subpass_inputs`. Fails if `id` has no such decoration.
+    pub fn get_input_attachment_index(&self, id: u32) -> Result<u32, ErrorCode> {
+        if !self
+            .compiler
+            .has_decoration(id, Decoration::InputAttachmentIndex)?
+        {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`id` has no `InputAttachmentIndex` decoration",
+            )));
+        }
+
+        self.compiler
+            .get_decoration(id, Decoration::InputAttachmentIndex)
+    }
+
+    /// Gets the range of the push constant block accessed by the current entry point, or `None`
+    /// if the module has no push constant block or the entry point doesn't access it.
+    pub fn get_push_constant_range(&self) -> Result<Option<PushConstantRange>, ErrorCode> {
+        let resource = match self
+            .compiler
+            .get_shader_resources()?
+            .push_constant_buffers
+            .first()
+        {
+            Some(resource) => resource.id,
+            None => return Ok(None),
+        };
+        let ranges = self.compiler.get_active_buffer_ranges(resource)?;
+        let start = match ranges.iter().map(|range| range.offset).min() {
+            Some(start) => start,
+            None => return Ok(None),
+        };
+        let end = ranges
+            .iter()
+            .map(|range| range.offset + range.range)
+            .max()
+            .unwrap_or(start);
+
+        Ok(Some(PushConstantRange {
+            offset: start as u32,
+            size: (end - start) as u32,
+            stages: vec![self.primary_stage()?],
+        }))
+    }
+
+    /// Gets the decorations active on a push constant block, such as `NonWritable`. Decorations
+    /// applied to every member of the block are reported as decorations of the block itself.
+    pub fn get_push_constant_decorations(&self, id: u32) -> Result<Vec<Decoration>, ErrorCode> {
+        let push_constant_buffers = self.compiler.get_shader_resources()?.push_constant_buffers;
+        if !push_constant_buffers
+            .iter()
+            .any(|resource| resource.id == id)
+        {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`id` must be a push constant buffer resource",
+            )));
+        }
+
+        self.compiler.get_buffer_block_decorations(id)
+    }
+
+    /// Gets the name the push constant block is declared with in the compiled output, such as
+    /// the name of the cbuffer or buffer it was remapped to. `compile` must be called first.
+    pub fn get_push_constant_block_name(&self) -> Result<String, ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        match self
+            .compiler
+            .get_shader_resources()?
+            .push_constant_buffers
+            .first()
+        {
+            Some(resource) => self.compiler.get_remapped_declared_block_name(resource.id),
+            None => Err(ErrorCode::CompilationError(String::from(
+                "module has no push constant buffer",
+            ))),
+        }
+    }
+
+    /// Gets the name of the source file recorded by `OpSource` in the module's debug
+    /// information, or `None` if the module doesn't record one.
+    pub fn get_source_file_name(&self) -> Result<Option<String>, ErrorCode> {
+        self.compiler.get_source_file_name()
+    }
+
+    /// Gets the shader stage of the current entry point.
+    pub fn primary_stage(&self) -> Result<ShaderStage, ErrorCode> {
+        std::convert::TryFrom::try_from(self.compiler.get_execution_model()?)
+    }
+
+    /// Checks whether the current entry point reads or writes `builtin`.
+    pub fn uses_builtin(&self, builtin: BuiltIn) -> Result<bool, ErrorCode> {
+        let builtin = built_in_as_raw(Some(builtin));
+        Ok(self
+            .compiler
+            .has_active_builtin(builtin, StorageClass::Input)?
+            || self
+                .compiler
+                .has_active_builtin(builtin, StorageClass::Output)?)
+    }
+
+    /// Gets the capabilities declared by the module.
+    pub fn get_declared_capabilities(&self) -> Result<Vec<Capability>, ErrorCode> {
+        self.compiler.get_declared_capabilities()
+    }
+
+    /// Checks whether the module uses buffer device addresses, either through the
+    /// `PhysicalStorageBuffer64` addressing model or the `PhysicalStorageBufferAddresses`
+    /// capability, in which case the corresponding device feature must be enabled.
+    pub fn uses_physical_storage_buffer(&self) -> Result<bool, ErrorCode> {
+        Ok(self.compiler.get_addressing_model()?
+            == spv::AddressingModel::PhysicalStorageBuffer64
+            || self
+                .compiler
+                .get_declared_capabilities()?
+                .contains(&Capability::PhysicalStorageBufferAddresses))
+    }
+
+    /// Checks whether the current entry point is a ray tracing stage.
+    pub fn is_ray_tracing_stage(&self) -> Result<bool, ErrorCode> {
+        Ok(matches!(
+            self.compiler.get_execution_model()?,
+            ExecutionModel::RayGenerationKhr
+                | ExecutionModel::IntersectionKhr
+                | ExecutionModel::AnyHitKhr
+                | ExecutionModel::ClosestHitKhr
+                | ExecutionModel::MissKhr
+                | ExecutionModel::CallableKhr
+        ))
+    }
+
+    /// Gets the geometry shader state declared by the execution modes of the current entry point.
+    pub fn get_geometry_info(&self) -> Result<GeometryInfo, ErrorCode> {
+        if self.compiler.get_execution_model()? != ExecutionModel::Geometry {
+            return Err(ErrorCode::CompilationError(String::from(
+                "entry point must be a geometry shader",
+            )));
+        }
+
+        let input_primitive = self.find_execution_mode(&[
+            (
+                spv::ExecutionMode::InputPoints,
+                GeometryInputPrimitive::Points,
+            ),
+            (
+                spv::ExecutionMode::InputLines,
+                GeometryInputPrimitive::Lines,
+            ),
+            (
+                spv::ExecutionMode::InputLinesAdjacency,
+                GeometryInputPrimitive::LinesAdjacency,
+            ),
+            (
+                spv::ExecutionMode::Triangles,
+                GeometryInputPrimitive::Triangles,
+            ),
+            (
+                spv::ExecutionMode::InputTrianglesAdjacency,
+                GeometryInputPrimitive::TrianglesAdjacency,
+            ),
+        ])?;
+        let output_primitive = self.find_execution_mode(&[
+            (
+                spv::ExecutionMode::OutputPoints,
+                GeometryOutputPrimitive::Points,
+            ),
+            (
+                spv::ExecutionMode::OutputLineStrip,
+                GeometryOutputPrimitive::LineStrip,
+            ),
+            (
+                spv::ExecutionMode::OutputTriangleStrip,
+                GeometryOutputPrimitive::TriangleStrip,
+            ),
+        ])?;
+        let output_vertices = self
+            .compiler
+            .get_execution_mode_argument(spv::ExecutionMode::OutputVertices, 0)?;
+        let invocations = if self
+            .compiler
+            .has_execution_mode(spv::ExecutionMode::Invocations)?
+        {
+            self.compiler
+                .get_execution_mode_argument(spv::ExecutionMode::Invocations, 0)?
+        } else {
+            1
+        };
+
+        Ok(GeometryInfo {
+            input_primitive,
+            output_primitive,
+            output_vertices,
+            invocations,
+        })
+    }
+
+    /// Gets the depth state declared by the execution modes of the current entry point, or the
+    /// default if no depth related execution mode is declared.
+    pub fn get_fragment_depth_mode(&self) -> Result<FragmentDepthMode, ErrorCode> {
+        if self.compiler.get_execution_model()? != ExecutionModel::Fragment {
+            return Err(ErrorCode::CompilationError(String::from(
+                "entry point must be a fragment shader",
+            )));
+        }
+
+        let early_fragment_tests = self
+            .compiler
+            .has_execution_mode(spv::ExecutionMode::EarlyFragmentTests)?;
+        let depth_replacing = self
+            .compiler
+            .has_execution_mode(spv::ExecutionMode::DepthReplacing)?;
+        let depth_layout = self.find_execution_mode(&[
+            (spv::ExecutionMode::DepthGreater, DepthLayout::Greater),
+            (spv::ExecutionMode::DepthLess, DepthLayout::Less),
+            (spv::ExecutionMode::DepthUnchanged, DepthLayout::Unchanged),
+        ])?;
+
+        Ok(FragmentDepthMode {
+            early_fragment_tests,
+            depth_replacing,
+            depth_layout,
+        })
+    }
+
+    /// Gets the tessellation state declared by the execution modes of the current entry point.
+    pub fn get_tessellation_info(&self) -> Result<TessellationInfo, ErrorCode> {
+        match self.compiler.get_execution_model()? {
+            ExecutionModel::TessellationControl | ExecutionModel::TessellationEvaluation => {}
+            _ => {
+                return Err(ErrorCode::CompilationError(String::from(
+                    "entry point must be a tessellation shader",
+                )))
+            }
+        }
+
+        let primitive = self.find_execution_mode(&[
+            (
+                spv::ExecutionMode::Triangles,
+                TessellationPrimitive::Triangles,
+            ),
+            (spv::ExecutionMode::Quads, TessellationPrimitive::Quads),
+            (
+                spv::ExecutionMode::Isolines,
+                TessellationPrimitive::Isolines,
+            ),
+        ])?;
+        let spacing = self.find_execution_mode(&[
+            (spv::ExecutionMode::SpacingEqual, TessellationSpacing::Equal),
+            (
+                spv::ExecutionMode::SpacingFractionalEven,
+                TessellationSpacing::FractionalEven,
+            ),
+            (
+                spv::ExecutionMode::SpacingFractionalOdd,
+                TessellationSpacing::FractionalOdd,
+            ),
+        ])?;
+        let winding = self.find_execution_mode(&[
+            (
+                spv::ExecutionMode::VertexOrderCw,
+                TessellationWinding::Clockwise,
+            ),
+            (
+                spv::ExecutionMode::VertexOrderCcw,
+                TessellationWinding::CounterClockwise,
+            ),
+        ])?;
+        let point_mode = self
+            .compiler
+            .has_execution_mode(spv::ExecutionMode::PointMode)?;
+        let output_vertices = if self
+            .compiler
+            .has_execution_mode(spv::ExecutionMode::OutputVertices)?
+        {
+            Some(
+                self.compiler
+                    .get_execution_mode_argument(spv::ExecutionMode::OutputVertices, 0)?,
+            )
+        } else {
+            None
+        };
+
+        Ok(TessellationInfo {
+            primitive,
+            spacing,
+            winding,
+            point_mode,
+            output_vertices,
+        })
+    }
+
+    fn find_execution_mode<T: Copy>(
+        &self,
+        modes: &[(spv::ExecutionMode, T)],
+    ) -> Result<Option<T>, ErrorCode> {
+        for &(mode, value) in modes {
+            if self.compiler.has_execution_mode(mode)? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Checks the preconditions for naming the synthesized stage output struct.
+    #[cfg(any(feature = "hlsl", feature = "msl"))]
+    pub(crate) fn check_stage_output_struct(&self) -> Result<(), ErrorCode> {
+        if !self.compiler.has_been_compiled {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`compile` must be called first",
+            )));
+        }
+
+        let resources = self.compiler.get_shader_resources()?;
+        if resources.stage_outputs.is_empty() && resources.builtin_outputs.is_empty() {
+            return Err(ErrorCode::CompilationError(String::from(
+                "entry point has no stage outputs",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Gets shader resources, only including those in `active_variables`. Useful with
+    /// `get_active_interface_variables` to reflect only the resources used by an entry point.
+    pub fn get_shader_resources_for_active_variables(
+        &self,
+        active_variables: &HashSet<u32>,
+    ) -> Result<ShaderResources, ErrorCode> {
+        self.compiler
+            .get_shader_resources_for_active_variables(active_variables)
+    }
+
+    /// Counts the descriptors of each type in each descriptor set, multiplying out array sizes.
+    /// Runtime-sized arrays and arrays sized by specialization constants count as one
+    /// descriptor. Images with `Dim::Buffer` are counted as texel buffers.
+    pub fn descriptor_set_summary(&self) -> Result<BTreeMap<u32, DescriptorCounts>, ErrorCode> {
+        let resources = self.compiler.get_shader_resources()?;
+        let mut summary = BTreeMap::<u32, DescriptorCounts>::new();
+        let uniform_texel_buffers: DescriptorCountField =
+            |counts| &mut counts.uniform_texel_buffers;
+        let storage_texel_buffers: DescriptorCountField =
+            |counts| &mut counts.storage_texel_buffers;
+        // Each kind of resource is paired with the count of its `Dim::Buffer` images, if any.
+        let kinds: [(
+            &[Resource],
+            DescriptorCountField,
+            Option<DescriptorCountField>,
+        ); 9] = [
+            (
+                &resources.uniform_buffers,
+                |counts| &mut counts.uniform_buffers,
+                None,
+            ),
+            (
+                &resources.storage_buffers,
+                |counts| &mut counts.storage_buffers,
+                None,
+            ),
+            (
+                &resources.subpass_inputs,
+                |counts| &mut counts.subpass_inputs,
+                None,
+            ),
+            (
+                &resources.storage_images,
+                |counts| &mut counts.storage_images,
+                Some(storage_texel_buffers),
+            ),
+            (
+                &resources.sampled_images,
+                |counts| &mut counts.sampled_images,
+                Some(uniform_texel_buffers),
+            ),
+            (
+                &resources.atomic_counters,
+                |counts| &mut counts.atomic_counters,
+                None,
+            ),
+            (
+                &resources.acceleration_structures,
+                |counts| &mut counts.acceleration_structures,
+                None,
+            ),
+            (
+                &resources.separate_images,
+                |counts| &mut counts.separate_images,
+                Some(uniform_texel_buffers),
+            ),
+            (
+                &resources.separate_samplers,
+                |counts| &mut counts.separate_samplers,
+                None,
+            ),
+        ];
+
+        for (kind_resources, count, texel_buffer_count) in kinds.iter() {
+            for resource in kind_resources.iter() {
+                let set = self
+                    .compiler
+                    .get_decoration(resource.id, Decoration::DescriptorSet)?;
+                let descriptors = self.descriptor_count(resource.type_id)?;
+                let count = match texel_buffer_count {
+                    Some(texel_buffer_count) if self.is_texel_buffer(resource.base_type_id)? => {
+                        texel_buffer_count
+                    }
+                    _ => count,
+                };
+                *count(summary.entry(set).or_default()) += descriptors;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Gets the descriptor sets holding at least one resource, in ascending order.
+    pub fn used_descriptor_sets(&self) -> Result<BTreeSet<u32>, ErrorCode> {
+        Ok(self.descriptor_set_summary()?.keys().cloned().collect())
+    }
+
+    /// Gets every shader resource other than built-ins along with its kind, descriptor set,
+    /// binding and location, in the order of the fields of `ShaderResources`.
+    pub fn resources_with_bindings(&self) -> Result<Vec<ResourceBindingInfo>, ErrorCode> {
+        let resources = self.compiler.get_shader_resources()?;
+        let kinds = [
+            (resources.uniform_buffers, ResourceKind::UniformBuffer),
+            (resources.storage_buffers, ResourceKind::StorageBuffer),
+            (resources.stage_inputs, ResourceKind::StageInput),
+            (resources.stage_outputs, ResourceKind::StageOutput),
+            (resources.subpass_inputs, ResourceKind::SubpassInput),
+            (resources.storage_images, ResourceKind::StorageImage),
+            (resources.sampled_images, ResourceKind::SampledImage),
+            (resources.atomic_counters, ResourceKind::AtomicCounter),
+            (
+                resources.acceleration_structures,
+                ResourceKind::AccelerationStructure,
+            ),
+            (
+                resources.push_constant_buffers,
+                ResourceKind::PushConstantBuffer,
+            ),
+            (
+                resources.shader_record_buffers,
+                ResourceKind::ShaderRecordBuffer,
+            ),
+            (resources.separate_images, ResourceKind::SeparateImage),
+            (resources.separate_samplers, ResourceKind::SeparateSampler),
+        ];
+
+        let mut infos = Vec::new();
+        for (kind_resources, kind) in kinds.iter() {
+            for resource in kind_resources {
+                infos.push(ResourceBindingInfo {
+                    set: self.get_optional_decoration(resource.id, Decoration::DescriptorSet)?,
+                    binding: self.get_optional_decoration(resource.id, Decoration::Binding)?,
+                    location: self.get_optional_decoration(resource.id, Decoration::Location)?,
+                    resource: resource.clone(),
+                    kind: *kind,
+                });
+            }
+        }
+
+        Ok(infos)
+    }
+
+    /// Gets the descriptor set, binding, location and input attachment index of a variable.
+    pub fn get_binding(&self, id: u32) -> Result<Binding, ErrorCode> {
+        Ok(Binding {
+            set: self.get_optional_decoration(id, Decoration::DescriptorSet)?,
+            binding: self.get_optional_decoration(id, Decoration::Binding)?,
+            location: self.get_optional_decoration(id, Decoration::Location)?,
+            input_attachment_index: self
+                .get_optional_decoration(id, Decoration::InputAttachmentIndex)?,
+        })
+    }
+
+    fn get_optional_decoration(
+        &self,
+        id: u32,
+        decoration: Decoration,
+    ) -> Result<Option<u32>, ErrorCode> {
+        if self.compiler.has_decoration(id, decoration)? {
+            Ok(Some(self.compiler.get_decoration(id, decoration)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns whether `type_id` is an image or sampled image with `Dim::Buffer`.
+    fn is_texel_buffer(&self, type_id: u32) -> Result<bool, ErrorCode> {
+        Ok(match self.compiler.get_type(type_id)? {
+            Type::Image { image, .. } | Type::SampledImage { image, .. } => {
+                image.dim == Dim::Buffer
+            }
+            _ => false,
+        })
+    }
+
+    pub(crate) fn descriptor_count(&self, type_id: u32) -> Result<u32, ErrorCode> {
+        let (array, array_size_literal) = match self.compiler.get_type(type_id)? {
+            Type::Struct {
+                array,
+                array_size_literal,
+                ..
+            }
+            | Type::Image {
+                array,
+                array_size_literal,
+                ..
+            }
+            | Type::SampledImage {
+                array,
+                array_size_literal,
+                ..
+            }
+            | Type::Sampler {
+                array,
+                array_size_literal,
+            }
+            | Type::AtomicCounter {
+                array,
+                array_size_literal,
+            }
+            | Type::AccelerationStructure {
+                array,
+                array_size_literal,
+            }
+            | Type::UInt {
+                array,
+                array_size_literal,
+                ..
+            } => (array, array_size_literal),
+            _ => return Ok(1),
+        };
+
+        Ok(array
+            .iter()
+            .zip(array_size_literal.iter())
+            .map(|(&size, &literal)| if literal && size > 0 { size } else { 1 })
+            .product())
+    }
+
+    /// Gets the SPIR-V type associated with an ID. Types are cached for the lifetime of the `Ast`.
     pub fn get_type(&self, id: u32) -> Result<Type, ErrorCode> {
         self.compiler.get_type(id)
     }
 
+    /// Gets the image type of an image or sampled image type, such as the `base_type_id` of a
+    /// texture resource.
+    pub fn get_image_type(&self, id: u32) -> Result<ImageType, ErrorCode> {
+        match self.compiler.get_type(id)? {
+            Type::Image { image, .. } | Type::SampledImage { image, .. } => Ok(image),
+            _ => Err(ErrorCode::CompilationError(String::from(
+                "`id` must be an image or sampled image type",
+            ))),
+        }
+    }
+
+    /// Gets the storage class of the type associated with an ID. Only pointer types, such as
+    /// the `type_id` of a `Resource`, have a storage class other than `Generic`.
+    pub fn get_storage_class(&self, id: u32) -> Result<StorageClass, ErrorCode> {
+        self.compiler.get_storage_class(id)
+    }
+
     /// Gets the identifier for a member located at `index` within an `OpTypeStruct`.
     pub fn get_member_name(&self, id: u32, index: u32) -> Result<String, ErrorCode> {
         self.compiler.get_member_name(id, index)
     }
 
+    /// Gets a member name like `get_member_name`, without allocating if it is not defined.
+    pub fn get_member_name_cow(&self, id: u32, index: u32) -> Result<Cow<'static, str>, ErrorCode> {
+        self.compiler.get_member_name_cow(id, index)
+    }
+
     /// Gets a decoration for a member located at `index` within an `OpTypeStruct`.
     pub fn get_member_decoration(
         &self,
@@ -700,20 +2685,66 @@ impl<TTarget> Ast<TTarget>
         decoration: Decoration,
         argument: u32,
     ) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
         self.compiler
             .set_member_decoration(id, index, decoration, argument)
     }
 
+    /// Gets the offset, size, strides and majorness of a member located at `index` within an
+    /// `OpTypeStruct`.
+    pub fn get_member_layout(&self, id: u32, index: u32) -> Result<MemberLayout, ErrorCode> {
+        self.compiler.get_member_layout(id, index)
+    }
+
+    /// Gets whether the matrix member located at `index` within an `OpTypeStruct` is stored in
+    /// row-major order. Members without a `RowMajor` decoration are column-major.
+    pub fn is_row_major(&self, id: u32, index: u32) -> Result<bool, ErrorCode> {
+        Ok(self
+            .compiler
+            .get_member_decoration(id, index, Decoration::RowMajor)?
+            != 0)
+    }
+
+    /// Gets the number of members of an `OpTypeStruct`, without reading the rest of the type.
+    /// Fails if `id` is not a struct type.
+    pub fn get_member_count(&self, id: u32) -> Result<u32, ErrorCode> {
+        self.compiler.get_member_count(id)
+    }
+
     /// Gets the effective size of a buffer block.
     pub fn get_declared_struct_size(&self, id: u32) -> Result<u32, ErrorCode> {
         self.compiler.get_declared_struct_size(id)
     }
 
+    /// Gets the effective size of a buffer block whose trailing runtime array holds
+    /// `array_size` elements.
+    pub fn get_declared_struct_size_runtime(
+        &self,
+        id: u32,
+        array_size: u32,
+    ) -> Result<u32, ErrorCode> {
+        self.compiler
+            .get_declared_struct_size_runtime_array(id, array_size)
+    }
+
     /// Gets the effective size of a buffer block struct member.
     pub fn get_declared_struct_member_size(&self, id: u32, index: u32) -> Result<u32, ErrorCode> {
         self.compiler.get_declared_struct_member_size(id, index)
     }
 
+    /// Gets the upper bound of ids in the module. All valid ids are less than this value.
+    pub fn get_id_bound(&self) -> Result<u32, ErrorCode> {
+        self.compiler.get_id_bound()
+    }
+
+    /// Lists the ids of the parsed IR along with their names, types and decorations, for
+    /// debugging reflection results. This is not a SPIR-V disassembly. The format is not
+    /// guaranteed to be stable across versions of SPIRV-Cross.
+    #[cfg(feature = "debug-ir")]
+    pub fn dump_ir(&self) -> Result<String, ErrorCode> {
+        self.compiler.dump_ir()
+    }
+
     /// Renames an interface variable.
     pub fn rename_interface_variable(
         &mut self,
@@ -721,16 +2752,58 @@ impl<TTarget> Ast<TTarget>
         location: u32,
         name: &str,
     ) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
         self.compiler
             .rename_interface_variable(resources, location, name)
     }
 
+    /// Renames the interface variable `id`, which must be decorated with `location`. Like
+    /// `rename_interface_variable`, struct members are renamed so that they match across stages.
+    pub fn set_interface_variable_name(
+        &mut self,
+        id: u32,
+        location: u32,
+        name: &str,
+    ) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        if self.compiler.get_decoration(id, Decoration::Location)? != location {
+            return Err(ErrorCode::CompilationError(String::from(
+                "`id` must be decorated with `location`",
+            )));
+        }
+
+        self.compiler.set_interface_variable_name(id, location, name)
+    }
+
     /// get the active interface variable.
     pub fn get_active_interface_variables(&mut self) -> Result<HashSet<u32>, ErrorCode> {
         self.compiler
             .get_active_interface_variables()
     }
 
+    /// Restricts the interface variables emitted when compiling to `ids`, such as those
+    /// returned by `get_active_interface_variables`.
+    pub fn set_enabled_interface_variables(&mut self, ids: &HashSet<u32>) -> Result<(), ErrorCode> {
+        self.clear_compile_cache();
+        self.compiler.set_enabled_interface_variables(ids)
+    }
+
+    /// Gets the active interface variables, split by storage class into stage inputs, stage
+    /// outputs and other resources.
+    pub fn get_active_io_variables(&mut self) -> Result<ActiveIo, ErrorCode> {
+        let mut active_io = ActiveIo::default();
+        for id in self.compiler.get_active_interface_variables()? {
+            let variables = match self.compiler.get_variable_storage_class(id)? {
+                StorageClass::Input => &mut active_io.inputs,
+                StorageClass::Output => &mut active_io.outputs,
+                _ => &mut active_io.resources,
+            };
+            variables.insert(id);
+        }
+
+        Ok(active_io)
+    }
+
     /// Gets work group size specialization constants.
     pub fn get_work_group_size_specialization_constants(
         &self,
@@ -738,12 +2811,150 @@ impl<TTarget> Ast<TTarget>
         self.compiler.get_work_group_size_specialization_constants()
     }
 
+    /// Gets where each specialization constant is used as a work group size or as the length
+    /// of an array type. Specialization constants used in other ways, or only through
+    /// specialization constant operations, are not listed.
+    pub fn get_specialization_constant_usages(
+        &self,
+    ) -> Result<Vec<(SpecializationConstant, SpecializationConstantUsage)>, ErrorCode> {
+        let constants = self.compiler.get_specialization_constants()?;
+        let find_constant = |id| constants.iter().find(|constant| constant.id == id);
+        let mut usages = Vec::new();
+
+        let work_group_size = self
+            .compiler
+            .get_work_group_size_specialization_constants()?;
+        for (dim, size) in [work_group_size.x, work_group_size.y, work_group_size.z]
+            .iter()
+            .enumerate()
+        {
+            if let Some(constant) = find_constant(size.id) {
+                usages.push((
+                    *constant,
+                    SpecializationConstantUsage::WorkGroupSize(dim as u32),
+                ));
+            }
+        }
+
+        for type_id in self.compiler.get_ids_for_type(IdType::Type)? {
+            if let Some((length, false)) = self.compiler.get_outer_array_dimension(type_id)? {
+                if let Some(constant) = find_constant(length) {
+                    usages.push((*constant, SpecializationConstantUsage::ArraySize(type_id)));
+                }
+            }
+        }
+
+        Ok(usages)
+    }
+
+    /// Compiles every entry point in the module separately, pruning unused
+    /// interface variables for each, and returns the name, execution model and
+    /// source of each entry point. The entry point and enabled interface variables set beforehand are
+    /// restored once done, including when compilation fails.
+    pub fn compile_all_entry_points(
+        &mut self,
+    ) -> Result<Vec<(String, ExecutionModel, String)>, ErrorCode> {
+        let selected_name = self.compiler.get_entry_point_name()?;
+        let selected_execution_model = self.compiler.get_execution_model()?;
+        let enabled_variables = self.compiler.enabled_interface_variables.clone();
+
+        let results = self.compile_each_entry_point();
+
+        let restored = self
+            .select_entry_point(&selected_name, selected_execution_model)
+            .and_then(|()| match &enabled_variables {
+                Some(variables) => self.compiler.set_enabled_interface_variables(variables),
+                // SPIRV-Cross can't go back to enabling every variable implicitly, but enabling
+                // each of them explicitly is equivalent.
+                None => {
+                    let variables = self
+                        .compiler
+                        .get_ids_for_type(IdType::Variable)?
+                        .into_iter()
+                        .collect();
+                    self.compiler.set_enabled_interface_variables(&variables)
+                }
+            });
+        self.compiler.enabled_interface_variables = enabled_variables;
+
+        let results = results?;
+        restored?;
+        Ok(results)
+    }
+
+    fn compile_each_entry_point(
+        &mut self,
+    ) -> Result<Vec<(String, ExecutionModel, String)>, ErrorCode> {
+        let entry_points = self.get_entry_points()?;
+        let mut results = Vec::with_capacity(entry_points.len());
+        for entry_point in entry_points {
+            self.select_entry_point(&entry_point.name, entry_point.execution_model)?;
+            let active_variables = self.compiler.get_active_interface_variables()?;
+            self.compiler
+                .set_enabled_interface_variables(&active_variables)?;
+            let shader = self.compile()?;
+            results.push((entry_point.name, entry_point.execution_model, shader));
+        }
+
+        Ok(results)
+    }
+
+    /// Gets entry points, shader resources and specialization constants in a single call.
+    pub fn reflect(&self) -> Result<Reflection, ErrorCode> {
+        Ok(Reflection {
+            entry_points: self.get_entry_points()?,
+            shader_resources: self.get_shader_resources()?,
+            specialization_constants: self.get_specialization_constants()?,
+            work_group_size_specialization_constants: self
+                .get_work_group_size_specialization_constants()?,
+        })
+    }
+
     /// Parses a module into `Ast`.
     pub fn parse(module: &Module) -> Result<Self, ErrorCode> {
         Parse::<TTarget>::parse(module)
     }
 
+    /// Parses a module into `Ast` and applies compile options.
+    pub fn parse_with_options(
+        module: &Module,
+        options: &<Self as Compile<TTarget>>::CompilerOptions,
+    ) -> Result<Self, ErrorCode> {
+        let mut ast = Self::parse(module)?;
+        ast.set_compiler_options(options)?;
+        Ok(ast)
+    }
+
     /// Sets compile options.
+    ///
+    /// Each target has its own `CompilerOptions` type, so options for one target can't be
+    /// applied to an `Ast` of another.
+    #[cfg_attr(
+        all(feature = "glsl", feature = "msl"),
+        doc = r#"
+```no_run
+use spirv_cross::{glsl, spirv};
+
+# fn main() -> Result<(), spirv_cross::ErrorCode> {
+let module = spirv::Module::from_words(&[]);
+let mut ast = spirv::Ast::<glsl::Target>::parse(&module)?;
+ast.set_compiler_options(&glsl::CompilerOptions::default())?;
+# Ok(())
+# }
+```
+
+```compile_fail
+use spirv_cross::{glsl, msl, spirv};
+
+# fn main() -> Result<(), spirv_cross::ErrorCode> {
+let module = spirv::Module::from_words(&[]);
+let mut ast = spirv::Ast::<glsl::Target>::parse(&module)?;
+ast.set_compiler_options(&msl::CompilerOptions::default())?;
+# Ok(())
+# }
+```
+"#
+    )]
     pub fn set_compiler_options(
         &mut self,
         options: &<Self as Compile<TTarget>>::CompilerOptions,
@@ -756,4 +2967,437 @@ impl<TTarget> Ast<TTarget>
         self.compiler.has_been_compiled = true;
         Compile::<TTarget>::compile(self)
     }
+
+    /// Compiles an abstract syntax tree to the raw bytes of the output of the specified
+    /// `TTarget`. For text targets, this is equivalent to `compile().map(String::into_bytes)`.
+    pub fn compile_bytes(&mut self) -> Result<Vec<u8>, ErrorCode> {
+        self.compiler.has_been_compiled = true;
+        Compile::<TTarget>::compile_bytes(self)
+    }
+
+    /// Applies `options` and compiles, reusing the output of an earlier call with equal
+    /// options instead of compiling again. The options are applied even when cached output is
+    /// returned, so later calls such as `compile` use them too.
+    ///
+    /// Cached output is freed by `clear_compile_cache` and by any method that changes the
+    /// compiled output, such as renaming, redecorating, selecting another entry point or
+    /// setting a specialization constant. Otherwise memory grows with each distinct set of
+    /// options.
+    pub fn compile_cached(
+        &mut self,
+        options: &<Self as Compile<TTarget>>::CompilerOptions,
+    ) -> Result<Rc<String>, ErrorCode>
+    where
+        <Self as Compile<TTarget>>::CompilerOptions: Clone + Hash + Eq + 'static,
+    {
+        self.set_compiler_options(options)?;
+
+        let mut hasher = DefaultHasher::new();
+        options.hash(&mut hasher);
+        let key = hasher.finish();
+        if let Some((cached_options, shader)) = self.compiler.compile_cache.get(&key) {
+            if cached_options.downcast_ref() == Some(options) {
+                return Ok(Rc::clone(shader));
+            }
+        }
+
+        let shader = Rc::new(self.compile()?);
+        self.compiler
+            .compile_cache
+            .insert(key, (Box::new(options.clone()), Rc::clone(&shader)));
+        Ok(shader)
+    }
+
+    /// Frees the output cached by `compile_cached`.
+    pub fn clear_compile_cache(&mut self) {
+        self.compiler.compile_cache.clear();
+    }
+}
+
+/// A mismatch between the outputs of a producing stage and the inputs of a consuming stage.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InterfaceMismatch {
+    /// The consumer reads a location which the producer does not write.
+    MissingOutput { location: u32, input_type: Type },
+    /// The producer and consumer declare different types at the same location.
+    TypeMismatch {
+        location: u32,
+        output_type: Type,
+        input_type: Type,
+    },
+    /// A stage output of the producer has no `Location` decoration.
+    MissingOutputLocation { name: String },
+    /// A stage input of the consumer has no `Location` decoration.
+    MissingInputLocation { name: String },
+    /// Reflecting either stage failed.
+    Reflection(ErrorCode),
+}
+
+impl From<ErrorCode> for InterfaceMismatch {
+    fn from(error: ErrorCode) -> Self {
+        InterfaceMismatch::Reflection(error)
+    }
+}
+
+impl std::fmt::Display for InterfaceMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            InterfaceMismatch::MissingOutput {
+                location,
+                input_type,
+            } => write!(
+                f,
+                "location {}: producer writes nothing, consumer reads {:?}",
+                location, input_type
+            ),
+            InterfaceMismatch::TypeMismatch {
+                location,
+                output_type,
+                input_type,
+            } => write!(
+                f,
+                "location {}: producer writes {:?}, consumer reads {:?}",
+                location, output_type, input_type
+            ),
+            InterfaceMismatch::MissingOutputLocation { name } => {
+                write!(f, "producer output `{}` has no location", name)
+            }
+            InterfaceMismatch::MissingInputLocation { name } => {
+                write!(f, "consumer input `{}` has no location", name)
+            }
+            InterfaceMismatch::Reflection(error) => {
+                write!(f, "failed to reflect the stage interface: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InterfaceMismatch {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InterfaceMismatch::Reflection(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "backend")]
+/// Checks that every stage input of `consumer` is written by a stage output of `producer`
+/// at the same location and with the same type. Every stage input and output must have a
+/// `Location` decoration.
+#[allow(clippy::result_large_err)]
+pub fn validate_stage_interface<TProducer, TConsumer>(
+    producer: &Ast<TProducer>,
+    consumer: &Ast<TConsumer>,
+) -> Result<(), InterfaceMismatch>
+where
+    TProducer: Target,
+    TConsumer: Target,
+{
+    let producer = &producer.compiler;
+    let consumer = &consumer.compiler;
+
+    let mut outputs = std::collections::HashMap::new();
+    for output in producer.get_shader_resources()?.stage_outputs {
+        if !producer.has_decoration(output.id, Decoration::Location)? {
+            return Err(InterfaceMismatch::MissingOutputLocation { name: output.name });
+        }
+        let location = producer.get_decoration(output.id, Decoration::Location)?;
+        outputs.insert(location, producer.get_type(output.type_id)?);
+    }
+
+    for input in consumer.get_shader_resources()?.stage_inputs {
+        if !consumer.has_decoration(input.id, Decoration::Location)? {
+            return Err(InterfaceMismatch::MissingInputLocation { name: input.name });
+        }
+        let location = consumer.get_decoration(input.id, Decoration::Location)?;
+        let input_type = consumer.get_type(input.type_id)?;
+        match outputs.get(&location) {
+            None => {
+                return Err(InterfaceMismatch::MissingOutput {
+                    location,
+                    input_type,
+                })
+            }
+            Some(output_type) => {
+                if !types_match(producer, output_type, consumer, &input_type)? {
+                    return Err(InterfaceMismatch::TypeMismatch {
+                        location,
+                        output_type: output_type.clone(),
+                        input_type,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Keywords, reserved words and built-in function names that SPIRV-Cross renames when they
+/// are used as identifiers, excluding the vector, matrix, sampler and image type families
+/// matched by `is_reserved_glsl_name`.
+#[cfg(any(feature = "glsl", feature = "hlsl", feature = "msl"))]
+#[rustfmt::skip]
+const RESERVED_NAMES: &[&str] = &[
+    "abs", "acos", "acosh", "active", "all", "any", "asin", "asinh", "asm", "atan", "atanh",
+    "atomicAdd", "atomicAnd", "atomicCompSwap", "atomicCounter", "atomicCounterDecrement",
+    "atomicCounterIncrement", "atomicExchange", "atomicMax", "atomicMin", "atomicOr",
+    "atomicXor", "atomic_uint", "attribute", "barrier", "bitCount", "bitfieldExtract",
+    "bitfieldInsert", "bitfieldReverse", "bool", "break", "buffer", "case", "cast", "ceil",
+    "centroid", "class", "clamp", "coherent", "common", "const", "continue", "cos", "cosh",
+    "cross", "dFdx", "dFdxCoarse", "dFdxFine", "dFdy", "dFdyCoarse", "dFdyFine", "default",
+    "degrees", "determinant", "discard", "distance", "do", "dot", "double", "else",
+    "EmitStreamVertex", "EmitVertex", "EndPrimitive", "EndStreamPrimitive", "enum", "equal",
+    "exp", "exp2", "extern", "external", "faceforward", "false", "filter", "findLSB",
+    "findMSB", "fixed", "flat", "float", "floatBitsToInt", "floatBitsToUint", "floor", "fma",
+    "for", "fract", "frexp", "fwidth", "fwidthCoarse", "fwidthFine", "goto", "greaterThan",
+    "greaterThanEqual", "groupMemoryBarrier", "half", "highp", "if", "imageAtomicAdd",
+    "imageAtomicAnd", "imageAtomicCompSwap", "imageAtomicExchange", "imageAtomicMax",
+    "imageAtomicMin", "imageAtomicOr", "imageAtomicXor", "imageLoad", "imageSamples",
+    "imageSize", "imageStore", "imulExtended", "in", "inline", "inout", "input", "int",
+    "intBitsToFloat", "interface", "interpolateAtCentroid", "interpolateAtOffset",
+    "interpolateAtSample", "inverse", "inversesqrt", "invariant", "isinf", "isnan", "layout",
+    "ldexp", "length", "lessThan", "lessThanEqual", "log", "log2", "long", "lowp",
+    "matrixCompMult", "max", "mediump", "memoryBarrier", "memoryBarrierAtomicCounter",
+    "memoryBarrierBuffer", "memoryBarrierImage", "memoryBarrierShared", "min", "mix", "mod",
+    "modf", "namespace", "noinline", "noise", "noise1", "noise2", "noise3", "noise4",
+    "noperspective", "normalize", "not", "notEqual", "out", "outerProduct", "output",
+    "packDouble2x32", "packHalf2x16", "packSnorm2x16", "packSnorm4x8", "packUnorm2x16",
+    "packUnorm4x8", "packed", "partition", "patch", "pow", "precise", "precision", "public",
+    "radians", "readonly", "reflect", "refract", "resource", "restrict", "return", "round",
+    "roundEven", "sample", "shared", "short", "sign", "sin", "sinh", "sizeof", "smooth",
+    "smoothstep", "sqrt", "static", "step", "struct", "subroutine", "superp", "switch", "tan",
+    "tanh", "template", "texelFetch", "texelFetchOffset", "texture", "textureGather",
+    "textureGatherOffset", "textureGatherOffsets", "textureGrad", "textureGradOffset",
+    "textureLod", "textureLodOffset", "textureOffset", "textureProj", "textureProjGrad",
+    "textureProjGradOffset", "textureProjLod", "textureProjLodOffset", "textureProjOffset",
+    "textureQueryLevels", "textureQueryLod", "textureSamples", "textureSize", "this",
+    "transpose", "true", "trunc", "typedef", "uaddCarry", "uint", "uintBitsToFloat",
+    "umulExtended", "uniform", "union", "unpackDouble2x32", "unpackHalf2x16",
+    "unpackSnorm2x16", "unpackSnorm4x8", "unpackUnorm2x16", "unpackUnorm4x8", "unsigned",
+    "using", "usubBorrow", "varying", "void", "volatile", "while", "writeonly",
+];
+
+#[cfg(any(feature = "glsl", feature = "hlsl", feature = "msl"))]
+/// Returns whether SPIRV-Cross treats `name` as a reserved GLSL identifier. Every target
+/// renames these, as the HLSL and MSL compilers extend the GLSL one.
+pub(crate) fn is_reserved_glsl_name(name: &str) -> bool {
+    if RESERVED_NAMES.contains(&name) {
+        return true;
+    }
+
+    let is_vector = |rest: &str| matches!(rest, "2" | "3" | "4");
+    let is_matrix = |rest: &str| {
+        is_vector(rest)
+            || (rest.len() == 3
+                && is_vector(&rest[..1])
+                && &rest[1..2] == "x"
+                && is_vector(&rest[2..]))
+    };
+    let is_opaque = |rest: &str| {
+        rest.starts_with(|c: char| c.is_ascii_digit())
+            || rest.starts_with("Buffer")
+            || rest.starts_with("Cube")
+            || rest.starts_with("Rect")
+            || rest == "Shadow"
+    };
+
+    ["", "b", "d", "f", "h", "i", "u"]
+        .iter()
+        .any(|prefix| match name.strip_prefix(prefix) {
+            Some(rest) => {
+                rest.strip_prefix("vec").is_some_and(is_vector)
+                    || (matches!(*prefix, "" | "d" | "f" | "h")
+                        && rest.strip_prefix("mat").is_some_and(is_matrix))
+                    || (matches!(*prefix, "" | "i" | "u")
+                        && (rest.strip_prefix("sampler").is_some_and(is_opaque)
+                            || rest.strip_prefix("image").is_some_and(is_opaque)))
+            }
+            None => false,
+        })
+}
+
+#[cfg(feature = "backend")]
+/// Checks that `name` is an identifier accepted by every target language. Names beginning with
+/// `gl_` or containing `__` are reserved by GLSL, and the latter also by HLSL and MSL.
+fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.starts_with("gl_")
+        && !name.contains("__")
+}
+
+#[cfg(feature = "backend")]
+/// Compares types structurally, since member type ids are local to each module. Array sizes
+/// given by specialization constants are compared by their current values.
+fn types_match<TProducerData, TConsumerData>(
+    producer: &compiler::Compiler<TProducerData>,
+    output_type: &Type,
+    consumer: &compiler::Compiler<TConsumerData>,
+    input_type: &Type,
+) -> Result<bool, ErrorCode> {
+    let output_type = resolve_array_sizes(producer, output_type)?;
+    let input_type = resolve_array_sizes(consumer, input_type)?;
+    match (&output_type, &input_type) {
+        (
+            Type::Struct {
+                member_types: output_members,
+                array: output_array,
+                ..
+            },
+            Type::Struct {
+                member_types: input_members,
+                array: input_array,
+                ..
+            },
+        ) => {
+            if output_array != input_array || output_members.len() != input_members.len() {
+                return Ok(false);
+            }
+            for (&output_member, &input_member) in output_members.iter().zip(input_members) {
+                let output_member_type = producer.get_type(output_member)?;
+                let input_member_type = consumer.get_type(input_member)?;
+                if !types_match(producer, &output_member_type, consumer, &input_member_type)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        _ => Ok(output_type == input_type),
+    }
+}
+
+#[cfg(feature = "backend")]
+/// Replaces each array size of `ty` given by a constant with the value of that constant, so
+/// that it can be compared with types from another module.
+fn resolve_array_sizes<TTargetData>(
+    compiler: &compiler::Compiler<TTargetData>,
+    ty: &Type,
+) -> Result<Type, ErrorCode> {
+    let mut ty = ty.clone();
+    let (array, array_size_literal) = match &mut ty {
+        Type::Unknown | Type::Void => return Ok(ty),
+        Type::Boolean {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Char {
+            array,
+            array_size_literal,
+        }
+        | Type::Int {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::UInt {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Int64 {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::UInt64 {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::AtomicCounter {
+            array,
+            array_size_literal,
+        }
+        | Type::Half {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Float {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Double {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Struct {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Image {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::SampledImage {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Sampler {
+            array,
+            array_size_literal,
+        }
+        | Type::SByte {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::UByte {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Short {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::UShort {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::ControlPointArray {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::AccelerationStructure {
+            array,
+            array_size_literal,
+        }
+        | Type::RayQuery {
+            array,
+            array_size_literal,
+        }
+        | Type::Interpolant {
+            array,
+            array_size_literal,
+            ..
+        }
+        | Type::Pointer {
+            array,
+            array_size_literal,
+            ..
+        } => (array, array_size_literal),
+    };
+    for (length, is_literal) in array.iter_mut().zip(array_size_literal.iter_mut()) {
+        if !*is_literal {
+            *length = compiler.get_scalar_constant(*length)? as u32;
+            *is_literal = true;
+        }
+    }
+    Ok(ty)
 }
@@ -5,6 +5,7 @@
 #include "vendor/SPIRV-Cross/spirv_hlsl.hpp"
 #include "vendor/SPIRV-Cross/spirv_msl.hpp"
 #include "vendor/SPIRV-Cross/spirv_glsl.hpp"
+#include "vendor/SPIRV-Cross/spirv_parser.hpp"
 #include "wrapper.hpp"
 
 static const char *latest_exception_message;
@@ -50,7 +51,23 @@ extern "C"
 {
     ScInternalResult sc_internal_get_latest_exception_message(const char **message)
     {
-        INTERNAL_RESULT(*message = latest_exception_message;)
+        INTERNAL_RESULT(
+            do {
+                *message = latest_exception_message;
+                latest_exception_message = nullptr;
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_parse(const uint32_t *ir, const size_t size, bool *has_entry_points, spv::AddressingModel *addressing_model)
+    {
+        INTERNAL_RESULT(
+            do {
+                spirv_cross::Parser parser(ir, size);
+                parser.parse();
+                auto const &parsed_ir = parser.get_parsed_ir();
+                *has_entry_points = !parsed_ir.entry_points.empty();
+                *addressing_model = parsed_ir.addressing_model;
+            } while (0);)
     }
 
 #ifdef SPIRV_CROSS_WRAPPER_HLSL
@@ -77,6 +94,8 @@ extern "C"
                 hlsl_options.point_coord_compat = options->point_coord_compat;
                 hlsl_options.force_storage_buffer_as_uav = options->force_storage_buffer_as_uav;
                 hlsl_options.nonwritable_uav_texture_as_srv = options->nonwritable_uav_texture_as_srv;
+                hlsl_options.flatten_matrix_vertex_input_semantics = options->flatten_matrix_vertex_input_semantics;
+                hlsl_options.preserve_structured_buffers = options->preserve_structured_buffers;
 
                 compiler_hlsl->set_hlsl_options(hlsl_options);
             } while (0);)
@@ -101,6 +120,22 @@ extern "C"
                 compiler_hlsl->set_root_constant_layouts(root_constants);
             } while (0);)
     }
+
+    ScInternalResult sc_internal_compiler_hlsl_get_shader_model(const ScInternalCompilerHlsl *compiler, int32_t *shader_model)
+    {
+        INTERNAL_RESULT(*shader_model = ((spirv_cross::CompilerHLSL *)compiler)->get_hlsl_options().shader_model;)
+    }
+
+    ScInternalResult sc_internal_compiler_hlsl_set_shader_model(const ScInternalCompilerHlsl *compiler, const int32_t shader_model)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto compiler_hlsl = (spirv_cross::CompilerHLSL *)compiler;
+                auto hlsl_options = compiler_hlsl->get_hlsl_options();
+                hlsl_options.shader_model = shader_model;
+                compiler_hlsl->set_hlsl_options(hlsl_options);
+            } while (0);)
+    }
 #endif
 
 #ifdef SPIRV_CROSS_WRAPPER_MSL
@@ -168,6 +203,18 @@ extern "C"
                 msl_options.pad_fragment_output_components = options->pad_fragment_output_components;
                 msl_options.force_native_arrays = options->force_native_arrays;
                 msl_options.force_active_argument_buffer_resources = options->force_active_argument_buffer_resources;
+                msl_options.multiview = options->multiview;
+                msl_options.multiview_layered_rendering = options->multiview_layered_rendering;
+                msl_options.view_mask_buffer_index = options->view_mask_buffer_index;
+                msl_options.device_index = options->device_index;
+                msl_options.texture_buffer_native = options->texture_buffer_native;
+                msl_options.sample_dref_lod_array_as_grad = options->sample_dref_lod_array_as_grad;
+                msl_options.ios_support_base_vertex_instance = options->ios_support_base_vertex_instance;
+                msl_options.emulate_subgroups = options->emulate_subgroups;
+                msl_options.fixed_subgroup_size = options->fixed_subgroup_size;
+                msl_options.emulate_cube_array = options->emulate_cube_array;
+                msl_options.r32ui_linear_texture_alignment = options->r32ui_linear_texture_alignment;
+                msl_options.r32ui_alignment_constant_id = options->r32ui_alignment_constant_id;
                 compiler_msl->set_msl_options(msl_options);
             } while (0);)
     }
@@ -177,10 +224,77 @@ extern "C"
         INTERNAL_RESULT(*is_rasterization_disabled = ((spirv_cross::CompilerMSL *)compiler)->get_is_rasterization_disabled();)
     }
 
+    ScInternalResult sc_internal_compiler_msl_get_spirv(const ScInternalCompilerMsl *compiler, const uint32_t **words, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &ir = ((const spirv_cross::CompilerMSL *)compiler)->get_ir();
+                *words = ir.spirv.data();
+                *size = ir.spirv.size();
+            } while (0);)
+    }
+
     ScInternalResult sc_internal_compiler_get_automatic_msl_resource_binding(const ScInternalCompilerMsl *compiler, uint32_t id, uint32_t *result)
     {
         INTERNAL_RESULT(*result = ((spirv_cross::CompilerMSL *)compiler)->get_automatic_msl_resource_binding(id);)
     }
+
+    ScInternalResult sc_internal_compiler_msl_set_argument_buffer_device_address_space(const ScInternalCompilerMsl *compiler, uint32_t desc_set, bool device_storage)
+    {
+        INTERNAL_RESULT(((spirv_cross::CompilerMSL *)compiler)->set_argument_buffer_device_address_space(desc_set, device_storage);)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_add_dynamic_buffer(const ScInternalCompilerMsl *compiler, uint32_t desc_set, uint32_t binding, uint32_t index)
+    {
+        INTERNAL_RESULT(((spirv_cross::CompilerMSL *)compiler)->add_dynamic_buffer(desc_set, binding, index);)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_add_inline_uniform_block(const ScInternalCompilerMsl *compiler, uint32_t desc_set, uint32_t binding)
+    {
+        INTERNAL_RESULT(((spirv_cross::CompilerMSL *)compiler)->add_inline_uniform_block(desc_set, binding);)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_set_combined_sampler_suffix(const ScInternalCompilerMsl *compiler, const char *suffix)
+    {
+        INTERNAL_RESULT(((spirv_cross::CompilerMSL *)compiler)->set_combined_sampler_suffix(suffix);)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_get_combined_sampler_suffix(const ScInternalCompilerMsl *compiler, const char **suffix)
+    {
+        INTERNAL_RESULT(*suffix = strdup(((spirv_cross::CompilerMSL *)compiler)->get_combined_sampler_suffix());)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_buffer_requires_array_length(const ScInternalCompilerMsl *compiler, uint32_t id, bool *result)
+    {
+        INTERNAL_RESULT(*result = ((spirv_cross::CompilerMSL *)compiler)->buffer_requires_array_length(id);)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_set_enabled_fragment_outputs(const ScInternalCompilerMsl *compiler, uint32_t mask)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto compiler_msl = (spirv_cross::CompilerMSL *)compiler;
+                auto msl_options = compiler_msl->get_msl_options();
+                msl_options.enable_frag_output_mask = mask;
+                compiler_msl->set_msl_options(msl_options);
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_get_version(const ScInternalCompilerMsl *compiler, uint32_t *version)
+    {
+        INTERNAL_RESULT(*version = ((spirv_cross::CompilerMSL *)compiler)->get_msl_options().msl_version;)
+    }
+
+    ScInternalResult sc_internal_compiler_msl_set_version(const ScInternalCompilerMsl *compiler, const uint32_t version)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto compiler_msl = (spirv_cross::CompilerMSL *)compiler;
+                auto msl_options = compiler_msl->get_msl_options();
+                msl_options.msl_version = version;
+                compiler_msl->set_msl_options(msl_options);
+            } while (0);)
+    }
 #endif
 
 #ifdef SPIRV_CROSS_WRAPPER_GLSL
@@ -207,6 +321,7 @@ extern "C"
                 glsl_options.emit_line_directives = options->emit_line_directives;
                 glsl_options.enable_storage_image_qualifier_deduction = options->enable_storage_image_qualifier_deduction;
                 glsl_options.force_zero_initialized_variables = options->force_zero_initialized_variables;
+                glsl_options.force_flattened_io_blocks = options->force_flattened_io_blocks;
                 glsl_options.vertex.fixup_clipspace = options->vertex_transform_clip_space;
                 glsl_options.vertex.flip_vert_y = options->vertex_invert_y;
                 glsl_options.vertex.support_nonzero_base_instance = options->vertex_support_nonzero_base_instance;
@@ -216,6 +331,38 @@ extern "C"
             } while (0);)
     }
 
+    ScInternalResult sc_internal_compiler_glsl_get_version(const ScInternalCompilerGlsl *compiler, uint32_t *version, bool *es)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &glsl_options = ((spirv_cross::CompilerGLSL *)compiler)->get_common_options();
+                *version = glsl_options.version;
+                *es = glsl_options.es;
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_glsl_set_version(const ScInternalCompilerGlsl *compiler, const uint32_t version, const bool es)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto compiler_glsl = (spirv_cross::CompilerGLSL *)compiler;
+                auto glsl_options = compiler_glsl->get_common_options();
+                glsl_options.version = version;
+                glsl_options.es = es;
+                compiler_glsl->set_common_options(glsl_options);
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_glsl_get_spirv(const ScInternalCompilerGlsl *compiler, const uint32_t **words, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &ir = ((const spirv_cross::CompilerGLSL *)compiler)->get_ir();
+                *words = ir.spirv.data();
+                *size = ir.spirv.size();
+            } while (0);)
+    }
+
     ScInternalResult sc_internal_compiler_glsl_build_combined_image_samplers(const ScInternalCompilerBase *compiler)
     {
         INTERNAL_RESULT(
@@ -242,6 +389,22 @@ extern "C"
             } while (0);)
     }
 
+    ScInternalResult sc_internal_compiler_glsl_get_required_extensions(const ScInternalCompilerBase *compiler, const char ***extensions, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &sc_extensions = ((spirv_cross::CompilerGLSL *)compiler)->get_required_extensions();
+                auto const sc_size = sc_extensions.size();
+
+                *extensions = (const char **)malloc(sc_size * sizeof(const char *));
+                *size = sc_size;
+                for (uint32_t i = 0; i < sc_size; i++)
+                {
+                    (*extensions)[i] = strdup(sc_extensions[i].c_str());
+                }
+            } while (0);)
+    }
+
     ScInternalResult sc_internal_compiler_glsl_flatten_buffer_block(const ScInternalCompilerBase *compiler, const uint32_t id)
     {
         INTERNAL_RESULT(
@@ -251,11 +414,109 @@ extern "C"
     }
 #endif
 
+#ifdef SPIRV_CROSS_WRAPPER_DEBUG_IR
+    ScInternalResult sc_internal_compiler_debug_get_id(const ScInternalCompilerBase *compiler, const uint32_t id, const char **kind, uint32_t *type_id)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto &sc_id = ((spirv_cross::Compiler *)compiler)->get_ir().ids[id];
+
+                *type_id = 0;
+                switch (sc_id.get_type())
+                {
+                case spirv_cross::TypeType:
+                    *kind = "Type";
+                    break;
+                case spirv_cross::TypeVariable:
+                    *kind = "Variable";
+                    *type_id = sc_id.get<spirv_cross::SPIRVariable>().basetype;
+                    break;
+                case spirv_cross::TypeConstant:
+                    *kind = sc_id.get<spirv_cross::SPIRConstant>().specialization ? "SpecConstant" : "Constant";
+                    *type_id = sc_id.get<spirv_cross::SPIRConstant>().constant_type;
+                    break;
+                case spirv_cross::TypeConstantOp:
+                    *kind = "SpecConstantOp";
+                    *type_id = sc_id.get<spirv_cross::SPIRConstantOp>().basetype;
+                    break;
+                case spirv_cross::TypeUndef:
+                    *kind = "Undef";
+                    *type_id = sc_id.get<spirv_cross::SPIRUndef>().basetype;
+                    break;
+                case spirv_cross::TypeFunction:
+                    *kind = "Function";
+                    *type_id = sc_id.get<spirv_cross::SPIRFunction>().return_type;
+                    break;
+                case spirv_cross::TypeExtension:
+                    *kind = "Extension";
+                    break;
+                case spirv_cross::TypeString:
+                    *kind = "String";
+                    break;
+                case spirv_cross::TypeNone:
+                    *kind = "";
+                    break;
+                default:
+                    *kind = "Other";
+                    break;
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_debug_get_decorations(const ScInternalCompilerBase *compiler, const uint32_t id, spv::Decoration **decorations, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &sc_flags = ((spirv_cross::Compiler *)compiler)->get_decoration_bitset(id);
+
+                std::vector<spv::Decoration> sc_decorations;
+                sc_flags.for_each_bit([&](uint32_t bit) { sc_decorations.push_back(static_cast<spv::Decoration>(bit)); });
+
+                auto const sc_size = sc_decorations.size();
+                *decorations = (spv::Decoration *)malloc(sc_size * sizeof(spv::Decoration));
+                *size = sc_size;
+                for (size_t i = 0; i < sc_size; i++)
+                {
+                    (*decorations)[i] = sc_decorations[i];
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_debug_get_member_decorations(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, spv::Decoration **decorations, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &sc_flags = ((spirv_cross::Compiler *)compiler)->get_member_decoration_bitset(id, index);
+
+                std::vector<spv::Decoration> sc_decorations;
+                sc_flags.for_each_bit([&](uint32_t bit) { sc_decorations.push_back(static_cast<spv::Decoration>(bit)); });
+
+                auto const sc_size = sc_decorations.size();
+                *decorations = (spv::Decoration *)malloc(sc_size * sizeof(spv::Decoration));
+                *size = sc_size;
+                for (size_t i = 0; i < sc_size; i++)
+                {
+                    (*decorations)[i] = sc_decorations[i];
+                }
+            } while (0);)
+    }
+#endif
+
     ScInternalResult sc_internal_compiler_get_decoration(const ScInternalCompilerBase *compiler, uint32_t *result, const uint32_t id, const spv::Decoration decoration)
     {
         INTERNAL_RESULT(*result = ((spirv_cross::Compiler *)compiler)->get_decoration(id, decoration);)
     }
 
+    ScInternalResult sc_internal_compiler_has_decoration(const ScInternalCompilerBase *compiler, bool *result, const uint32_t id, const spv::Decoration decoration)
+    {
+        INTERNAL_RESULT(*result = ((spirv_cross::Compiler *)compiler)->has_decoration(id, decoration);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_binary_offset_for_decoration(const ScInternalCompilerBase *compiler, bool *result, uint32_t *word_offset, const uint32_t id, const spv::Decoration decoration)
+    {
+        INTERNAL_RESULT(*result = ((spirv_cross::Compiler *)compiler)->get_binary_offset_for_decoration(id, decoration, *word_offset);)
+    }
+
     ScInternalResult sc_internal_compiler_unset_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const spv::Decoration decoration)
     {
         INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->unset_decoration(id, decoration);)
@@ -266,6 +527,21 @@ extern "C"
         INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->set_decoration(id, decoration, argument);)
     }
 
+    ScInternalResult sc_internal_compiler_get_decoration_string(const ScInternalCompilerBase *compiler, const char **result, const uint32_t id, const spv::Decoration decoration)
+    {
+        INTERNAL_RESULT(*result = strdup(((spirv_cross::Compiler *)compiler)->get_decoration_string(id, decoration).c_str());)
+    }
+
+    ScInternalResult sc_internal_compiler_get_member_decoration_string(const ScInternalCompilerBase *compiler, const char **result, const uint32_t id, const uint32_t index, const spv::Decoration decoration)
+    {
+        INTERNAL_RESULT(*result = strdup(((spirv_cross::Compiler *)compiler)->get_member_decoration_string(id, index, decoration).c_str());)
+    }
+
+    ScInternalResult sc_internal_compiler_set_decoration_string(const ScInternalCompilerBase *compiler, const uint32_t id, const spv::Decoration decoration, const char *argument)
+    {
+        INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->set_decoration_string(id, decoration, std::string(argument));)
+    }
+
     ScInternalResult sc_internal_compiler_get_name(const ScInternalCompilerBase *compiler, const uint32_t id, const char **name)
     {
         INTERNAL_RESULT(
@@ -285,6 +561,30 @@ extern "C"
         INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->set_member_name(id, index, std::string(name));)
     }
 
+    ScInternalResult sc_internal_compiler_strip_debug_info(const ScInternalCompilerBase *compiler)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto &comp = *((spirv_cross::Compiler *)compiler);
+                auto const &meta = comp.get_ir().meta;
+
+                spirv_cross::SmallVector<std::pair<spirv_cross::ID, size_t>> named_ids;
+                for (auto const &entry : meta)
+                {
+                    named_ids.push_back(std::make_pair(entry.first, entry.second.members.size()));
+                }
+
+                for (auto const &named_id : named_ids)
+                {
+                    comp.set_name(named_id.first, std::string());
+                    for (uint32_t i = 0; i < named_id.second; i++)
+                    {
+                        comp.set_member_name(named_id.first, i, std::string());
+                    }
+                }
+            } while (0);)
+    }
+
     ScInternalResult sc_internal_compiler_get_entry_points(const ScInternalCompilerBase *compiler, ScEntryPoint **entry_points, size_t *size)
     {
         INTERNAL_RESULT(
@@ -330,6 +630,26 @@ extern "C"
             } while (0);)
     }
 
+    ScInternalResult sc_internal_compiler_get_buffer_block_decorations(const ScInternalCompilerBase *compiler, uint32_t id, spv::Decoration **decorations, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &comp = *((spirv_cross::Compiler *)compiler);
+                auto const sc_flags = comp.get_buffer_block_flags(id);
+
+                std::vector<spv::Decoration> sc_decorations;
+                sc_flags.for_each_bit([&](uint32_t bit) { sc_decorations.push_back(static_cast<spv::Decoration>(bit)); });
+
+                auto const sc_size = sc_decorations.size();
+                *decorations = (spv::Decoration *)malloc(sc_size * sizeof(spv::Decoration));
+                *size = sc_size;
+                for (size_t i = 0; i < sc_size; i++)
+                {
+                    (*decorations)[i] = sc_decorations[i];
+                }
+            } while (0);)
+    }
+
     ScInternalResult sc_internal_compiler_get_cleansed_entry_point_name(const ScInternalCompilerBase *compiler, const char *original_entry_point_name, const spv::ExecutionModel execution_model, const char **compiled_entry_point_name)
     {
         INTERNAL_RESULT(
@@ -341,6 +661,47 @@ extern "C"
             } while (0);)
     }
 
+    ScInternalResult sc_internal_compiler_get_remapped_declared_block_name(const ScInternalCompilerBase *compiler, const uint32_t id, const char **name)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const block_name = ((spirv_cross::Compiler *)compiler)->get_remapped_declared_block_name(id);
+                *name = strdup(block_name.c_str());
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_source_file_name(const ScInternalCompilerBase *compiler, const char **name)
+    {
+        INTERNAL_RESULT(
+            do {
+                // The parser doesn't keep the file operand of OpSource, so find it in the module
+                auto const &ir = ((const spirv_cross::Compiler *)compiler)->get_ir();
+                *name = nullptr;
+                size_t offset = 5;
+                while (offset < ir.spirv.size())
+                {
+                    auto const word_count = ir.spirv[offset] >> 16;
+                    auto const op = static_cast<spv::Op>(ir.spirv[offset] & 0xffff);
+                    if (word_count == 0 || offset + word_count > ir.spirv.size())
+                    {
+                        break;
+                    }
+
+                    if (op == spv::OpSource && word_count >= 4)
+                    {
+                        auto const file = ir.spirv[offset + 3];
+                        if (file < ir.ids.size() && ir.ids[file].get_type() == spirv_cross::TypeString)
+                        {
+                            *name = strdup(ir.ids[file].get<spirv_cross::SPIRString>().str.c_str());
+                        }
+                        break;
+                    }
+
+                    offset += word_count;
+                }
+            } while (0);)
+    }
+
     void fill_resource_array(ScResourceArray *resources, const spirv_cross::SmallVector<spirv_cross::Resource> &sc_resources)
     {
         auto const sc_size = sc_resources.size();
@@ -391,27 +752,46 @@ extern "C"
         }
     }
 
+    void fill_shader_resources(ScShaderResources *shader_resources, const spirv_cross::ShaderResources &sc_resources)
+    {
+        fill_resource_array(&shader_resources->uniform_buffers, sc_resources.uniform_buffers);
+        fill_resource_array(&shader_resources->storage_buffers, sc_resources.storage_buffers);
+        fill_resource_array(&shader_resources->stage_inputs, sc_resources.stage_inputs);
+        fill_resource_array(&shader_resources->stage_outputs, sc_resources.stage_outputs);
+        fill_resource_array(&shader_resources->subpass_inputs, sc_resources.subpass_inputs);
+        fill_resource_array(&shader_resources->storage_images, sc_resources.storage_images);
+        fill_resource_array(&shader_resources->sampled_images, sc_resources.sampled_images);
+        fill_resource_array(&shader_resources->atomic_counters, sc_resources.atomic_counters);
+        fill_resource_array(&shader_resources->acceleration_structures, sc_resources.acceleration_structures);
+        fill_resource_array(&shader_resources->push_constant_buffers, sc_resources.push_constant_buffers);
+        fill_resource_array(&shader_resources->shader_record_buffers, sc_resources.shader_record_buffers);
+        fill_resource_array(&shader_resources->separate_images, sc_resources.separate_images);
+        fill_resource_array(&shader_resources->separate_samplers, sc_resources.separate_samplers);
+        fill_builtin_resource_array(&shader_resources->builtin_inputs, sc_resources.builtin_inputs);
+        fill_builtin_resource_array(&shader_resources->builtin_outputs, sc_resources.builtin_outputs);
+    }
+
     ScInternalResult sc_internal_compiler_get_shader_resources(const ScInternalCompilerBase *compiler, ScShaderResources *shader_resources)
     {
         INTERNAL_RESULT(
             do {
                 auto const sc_resources = ((const spirv_cross::Compiler *)compiler)->get_shader_resources();
+                fill_shader_resources(shader_resources, sc_resources);
+            } while (0);)
+    }
 
-                fill_resource_array(&shader_resources->uniform_buffers, sc_resources.uniform_buffers);
-                fill_resource_array(&shader_resources->storage_buffers, sc_resources.storage_buffers);
-                fill_resource_array(&shader_resources->stage_inputs, sc_resources.stage_inputs);
-                fill_resource_array(&shader_resources->stage_outputs, sc_resources.stage_outputs);
-                fill_resource_array(&shader_resources->subpass_inputs, sc_resources.subpass_inputs);
-                fill_resource_array(&shader_resources->storage_images, sc_resources.storage_images);
-                fill_resource_array(&shader_resources->sampled_images, sc_resources.sampled_images);
-                fill_resource_array(&shader_resources->atomic_counters, sc_resources.atomic_counters);
-                fill_resource_array(&shader_resources->acceleration_structures, sc_resources.acceleration_structures);
-                fill_resource_array(&shader_resources->push_constant_buffers, sc_resources.push_constant_buffers);
-                fill_resource_array(&shader_resources->shader_record_buffers, sc_resources.shader_record_buffers);
-                fill_resource_array(&shader_resources->separate_images, sc_resources.separate_images);
-                fill_resource_array(&shader_resources->separate_samplers, sc_resources.separate_samplers);
-                fill_builtin_resource_array(&shader_resources->builtin_inputs, sc_resources.builtin_inputs);
-                fill_builtin_resource_array(&shader_resources->builtin_outputs, sc_resources.builtin_outputs);
+    ScInternalResult sc_internal_compiler_get_shader_resources_for_active_variables(const ScInternalCompilerBase *compiler, ScShaderResources *shader_resources, const uint32_t *ids, const size_t size)
+    {
+        INTERNAL_RESULT(
+            do {
+                std::unordered_set<spirv_cross::VariableID> sc_active_variables;
+                for (size_t i = 0; i < size; i++)
+                {
+                    sc_active_variables.insert(ids[i]);
+                }
+
+                auto const sc_resources = ((const spirv_cross::Compiler *)compiler)->get_shader_resources(sc_active_variables);
+                fill_shader_resources(shader_resources, sc_resources);
             } while (0);)
     }
 
@@ -443,6 +823,116 @@ extern "C"
             } while (0);)
     }
 
+    ScInternalResult sc_internal_compiler_set_composite_constant(const ScInternalCompilerBase *compiler, const uint32_t id, const uint64_t *values, const size_t size, size_t *component_count)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto &comp = *((spirv_cross::Compiler *)compiler);
+
+                // Each component of the composite, flattened, along with the constant it refers to,
+                // if any, so both the inline value and the referenced constant are updated.
+                std::vector<std::pair<uint64_t *, uint32_t>> components;
+                std::function<void(spirv_cross::SPIRConstant &)> collect = [&](spirv_cross::SPIRConstant &constant) {
+                    if (!constant.subconstants.empty())
+                    {
+                        for (auto const subconstant : constant.subconstants)
+                        {
+                            collect(comp.get_constant(subconstant));
+                        }
+                        return;
+                    }
+
+                    for (uint32_t col = 0; col < constant.columns(); col++)
+                    {
+                        for (uint32_t row = 0; row < constant.vector_size(); row++)
+                        {
+                            components.emplace_back(&constant.m.c[col].r[row].u64, uint32_t(constant.m.c[col].id[row]));
+                        }
+                    }
+                };
+                collect(comp.get_constant(id));
+
+                *component_count = components.size();
+                if (components.size() != size)
+                {
+                    break;
+                }
+
+                for (size_t i = 0; i < size; i++)
+                {
+                    *components[i].first = values[i];
+                    if (components[i].second)
+                    {
+                        comp.get_constant(components[i].second).m.c[0].r[0].u64 = values[i];
+                    }
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_constants(const ScInternalCompilerBase *compiler, uint32_t **ids, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                std::vector<uint32_t> sc_ids;
+                ((const spirv_cross::Compiler *)compiler)->get_ir().for_each_typed_id<spirv_cross::SPIRConstant>([&](uint32_t id, const spirv_cross::SPIRConstant &constant) {
+                    if (!constant.specialization)
+                    {
+                        sc_ids.push_back(id);
+                    }
+                });
+
+                auto const sc_size = sc_ids.size();
+                *ids = (uint32_t *)malloc(sc_size * sizeof(uint32_t));
+                *size = sc_size;
+                for (size_t i = 0; i < sc_size; i++)
+                {
+                    (*ids)[i] = sc_ids[i];
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_ids_for_type(const ScInternalCompilerBase *compiler, const uint32_t type, uint32_t **ids, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &ir = ((const spirv_cross::Compiler *)compiler)->get_ir();
+                auto const sc_type = static_cast<spirv_cross::Types>(type);
+                std::vector<uint32_t> sc_ids;
+                for (auto &id : ir.ids_for_type[sc_type])
+                {
+                    if (ir.ids[id].get_type() == sc_type)
+                    {
+                        sc_ids.push_back(id);
+                    }
+                }
+
+                auto const sc_size = sc_ids.size();
+                *ids = (uint32_t *)malloc(sc_size * sizeof(uint32_t));
+                *size = sc_size;
+                for (size_t i = 0; i < sc_size; i++)
+                {
+                    (*ids)[i] = sc_ids[i];
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_scalar_constant(const ScInternalCompilerBase *compiler, const uint32_t id, bool *is_scalar, uint32_t *constant_high_bits, uint32_t *constant_low_bits)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &sc_constant = ((spirv_cross::Compiler *)compiler)->get_constant(id);
+                *is_scalar = sc_constant.subconstants.empty() && sc_constant.columns() == 1 && sc_constant.vector_size() == 1;
+                auto const value = sc_constant.m.c[0].r[0].u64;
+                *constant_high_bits = (uint32_t)(value >> 32);
+                *constant_low_bits = (uint32_t)value;
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_variable_type_id(const ScInternalCompilerBase *compiler, const uint32_t id, uint32_t *type_id)
+    {
+        INTERNAL_RESULT(*type_id = ((spirv_cross::Compiler *)compiler)->get_type_from_variable(id).self;)
+    }
+
     ScInternalResult sc_internal_compiler_get_type(const ScInternalCompilerBase *compiler, const uint32_t id, const ScType **spirv_type)
     {
         INTERNAL_RESULT(
@@ -459,6 +949,9 @@ extern "C"
                 ty->member_types_size = member_types_size;
                 ty->array_size = array_size;
                 ty->storage = type.storage;
+                ty->pointer = type.pointer;
+                ty->forward_pointer = type.forward_pointer;
+                ty->parent_type = type.parent_type;
 
                 if (member_types_size > 0)
                 {
@@ -515,6 +1008,15 @@ extern "C"
         INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->set_member_decoration(id, index, decoration, argument);)
     }
 
+    ScInternalResult sc_internal_compiler_get_member_count(const ScInternalCompilerBase *compiler, const uint32_t id, bool *is_struct, uint32_t *count)
+    {
+        INTERNAL_RESULT(do {
+            auto const &type = ((spirv_cross::Compiler *)compiler)->get_type(id);
+            *is_struct = type.basetype == spirv_cross::SPIRType::Struct;
+            *count = uint32_t(type.member_types.size());
+        } while (0);)
+    }
+
     ScInternalResult sc_internal_compiler_get_declared_struct_size(const ScInternalCompilerBase *compiler, const uint32_t id, uint32_t *result)
     {
         INTERNAL_RESULT(do {
@@ -523,6 +1025,14 @@ extern "C"
         } while (0);)
     }
 
+    ScInternalResult sc_internal_compiler_get_declared_struct_size_runtime_array(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t array_size, uint32_t *result)
+    {
+        INTERNAL_RESULT(do {
+            auto const &comp = ((spirv_cross::Compiler *)compiler);
+            *result = comp->get_declared_struct_size_runtime_array(comp->get_type(id), array_size);
+        } while (0);)
+    }
+
     ScInternalResult sc_internal_compiler_get_declared_struct_member_size(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, uint32_t *result)
     {
         INTERNAL_RESULT(do {
@@ -531,6 +1041,11 @@ extern "C"
         } while (0);)
     }
 
+    ScInternalResult sc_internal_compiler_get_id_bound(const ScInternalCompilerBase *compiler, uint32_t *result)
+    {
+        INTERNAL_RESULT(*result = ((const spirv_cross::Compiler *)compiler)->get_current_id_bound();)
+    }
+
     ScInternalResult sc_internal_compiler_rename_interface_variable(const ScInternalCompilerBase *compiler, const ScResource *resources, const size_t resources_size, uint32_t location, const char *name)
     {
         INTERNAL_RESULT(do {
@@ -553,6 +1068,23 @@ extern "C"
         } while (0);)
     }
 
+    ScInternalResult sc_internal_compiler_set_interface_variable_name(const ScInternalCompilerBase *compiler, uint32_t id, uint32_t location, const char *name)
+    {
+        INTERNAL_RESULT(do {
+            auto &comp = *(spirv_cross::Compiler *)compiler;
+
+            spirv_cross::Resource sc_resource;
+            sc_resource.id = id;
+            sc_resource.base_type_id = comp.get_type_from_variable(id).self;
+
+            spirv_cross::SmallVector<spirv_cross::Resource> sc_resources;
+            sc_resources.push_back(sc_resource);
+
+            std::string new_name(name);
+            spirv_cross_util::rename_interface_variable(comp, sc_resources, location, new_name);
+        } while (0);)
+    }
+
     ScInternalResult sc_internal_compiler_get_work_group_size_specialization_constants(const ScInternalCompilerBase *compiler, ScSpecializationConstant **constants)
     {
         INTERNAL_RESULT(do {
@@ -574,6 +1106,11 @@ extern "C"
     }
 
 
+    ScInternalResult sc_internal_compiler_get_variable_storage_class(const ScInternalCompilerBase *compiler, uint32_t id, spv::StorageClass *storage_class)
+    {
+        INTERNAL_RESULT(*storage_class = ((const spirv_cross::Compiler *)compiler)->get_storage_class(id);)
+    }
+
     ScInternalResult sc_internal_compiler_get_active_interface_variables(const ScInternalCompilerBase *compiler, uint32_t **ids, size_t* size) {
         INTERNAL_RESULT(do {
             auto const sc_active_variables = ((const spirv_cross::Compiler *)compiler)->get_active_interface_variables();
@@ -588,11 +1125,91 @@ extern "C"
         } while(0);)
     }
 
+    ScInternalResult sc_internal_compiler_set_enabled_interface_variables(const ScInternalCompilerBase *compiler, const uint32_t *ids, const size_t size)
+    {
+        INTERNAL_RESULT(do {
+            std::unordered_set<spirv_cross::VariableID> sc_active_variables;
+            for (size_t i = 0; i < size; i++)
+            {
+                sc_active_variables.insert(ids[i]);
+            }
+
+            ((spirv_cross::Compiler *)compiler)->set_enabled_interface_variables(std::move(sc_active_variables));
+        } while (0);)
+    }
+
     ScInternalResult sc_internal_compiler_set_entry_point(const ScInternalCompilerBase *compiler, const char *name, const spv::ExecutionModel execution_model)
     {
         INTERNAL_RESULT(((spirv_cross::Compiler *)compiler)->set_entry_point(name, execution_model);)
     }
 
+    ScInternalResult sc_internal_compiler_set_common_options(const ScInternalCompilerBase *compiler, const ScCommonOptions *options)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto compiler_glsl = (spirv_cross::CompilerGLSL *)compiler;
+                auto common_options = compiler_glsl->get_common_options();
+                common_options.flatten_multidimensional_arrays = options->flatten_multidimensional_arrays;
+                common_options.enable_row_major_load_workaround = options->enable_row_major_load_workaround;
+                common_options.ovr_multiview_view_count = options->ovr_multiview_view_count;
+                compiler_glsl->set_common_options(common_options);
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_execution_model(const ScInternalCompilerBase *compiler, spv::ExecutionModel *execution_model)
+    {
+        INTERNAL_RESULT(*execution_model = ((spirv_cross::Compiler *)compiler)->get_execution_model();)
+    }
+
+    ScInternalResult sc_internal_compiler_get_entry_point_name(const ScInternalCompilerBase *compiler, const char **name)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &ir = ((const spirv_cross::Compiler *)compiler)->get_ir();
+                *name = strdup(ir.entry_points.at(ir.default_entry_point).orig_name.c_str());
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_declared_capabilities(const ScInternalCompilerBase *compiler, spv::Capability **capabilities, size_t *size)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto const &sc_capabilities = ((spirv_cross::Compiler *)compiler)->get_declared_capabilities();
+                auto const sc_size = sc_capabilities.size();
+                *capabilities = (spv::Capability *)malloc(sc_size * sizeof(spv::Capability));
+                *size = sc_size;
+                for (size_t i = 0; i < sc_size; i++)
+                {
+                    (*capabilities)[i] = sc_capabilities[i];
+                }
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_addressing_model(const ScInternalCompilerBase *compiler, spv::AddressingModel *addressing_model)
+    {
+        INTERNAL_RESULT(*addressing_model = ((spirv_cross::Compiler *)compiler)->get_ir().addressing_model;)
+    }
+
+    ScInternalResult sc_internal_compiler_has_execution_mode(const ScInternalCompilerBase *compiler, const spv::ExecutionMode mode, bool *result)
+    {
+        INTERNAL_RESULT(*result = ((spirv_cross::Compiler *)compiler)->get_execution_mode_bitset().get(mode);)
+    }
+
+    ScInternalResult sc_internal_compiler_has_active_builtin(const ScInternalCompilerBase *compiler, const spv::BuiltIn builtin, const spv::StorageClass storage, bool *result)
+    {
+        INTERNAL_RESULT(
+            do {
+                auto compiler_base = (spirv_cross::Compiler *)compiler;
+                compiler_base->update_active_builtins();
+                *result = compiler_base->has_active_builtin(builtin, storage);
+            } while (0);)
+    }
+
+    ScInternalResult sc_internal_compiler_get_execution_mode_argument(const ScInternalCompilerBase *compiler, const spv::ExecutionMode mode, const uint32_t index, uint32_t *result)
+    {
+        INTERNAL_RESULT(*result = ((spirv_cross::Compiler *)compiler)->get_execution_mode_argument(mode, index);)
+    }
+
     ScInternalResult sc_internal_compiler_compile(const ScInternalCompilerBase *compiler, const char **shader)
     {
         INTERNAL_RESULT(*shader = strdup(((spirv_cross::Compiler *)compiler)->compile().c_str());)
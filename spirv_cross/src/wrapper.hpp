@@ -59,6 +59,8 @@ extern "C"
         bool force_storage_buffer_as_uav;
         bool nonwritable_uav_texture_as_srv;
         bool force_zero_initialized_variables;
+        bool flatten_matrix_vertex_input_semantics;
+        bool preserve_structured_buffers;
     } ScHlslCompilerOptions;
 
     typedef struct ScMslCompilerOptions
@@ -83,6 +85,18 @@ extern "C"
         bool force_native_arrays;
         bool force_zero_initialized_variables;
         bool force_active_argument_buffer_resources;
+        bool multiview;
+        bool multiview_layered_rendering;
+        uint32_t view_mask_buffer_index;
+        uint32_t device_index;
+        bool texture_buffer_native;
+        bool sample_dref_lod_array_as_grad;
+        bool ios_support_base_vertex_instance;
+        bool emulate_subgroups;
+        uint32_t fixed_subgroup_size;
+        bool emulate_cube_array;
+        uint32_t r32ui_linear_texture_alignment;
+        uint32_t r32ui_alignment_constant_id;
     } ScMslCompilerOptions;
 
     typedef struct ScGlslCompilerOptions
@@ -104,8 +118,16 @@ extern "C"
         bool emit_line_directives;
         bool enable_storage_image_qualifier_deduction;
         bool force_zero_initialized_variables;
+        bool force_flattened_io_blocks;
     } ScGlslCompilerOptions;
 
+    typedef struct ScCommonOptions
+    {
+        bool flatten_multidimensional_arrays;
+        bool enable_row_major_load_workaround;
+        uint32_t ovr_multiview_view_count;
+    } ScCommonOptions;
+
     typedef struct ScResource
     {
         uint32_t id;
@@ -170,14 +192,20 @@ extern "C"
         size_t array_size;
         spv::StorageClass storage;
         spirv_cross::SPIRType::ImageType image;
+        bool pointer;
+        bool forward_pointer;
+        uint32_t parent_type;
     } ScType;
 
     ScInternalResult sc_internal_get_latest_exception_message(const char **message);
+    ScInternalResult sc_internal_parse(const uint32_t *ir, const size_t size, bool *has_entry_points, spv::AddressingModel *addressing_model);
 
 #ifdef SPIRV_CROSS_WRAPPER_HLSL
     ScInternalResult sc_internal_compiler_hlsl_new(ScInternalCompilerHlsl **compiler, const uint32_t *ir, const size_t size);
     ScInternalResult sc_internal_compiler_hlsl_set_options(const ScInternalCompilerHlsl *compiler, const ScHlslCompilerOptions *options);
     ScInternalResult sc_internal_compiler_hlsl_set_root_constant_layout(const ScInternalCompilerHlsl *compiler, const ScHlslRootConstant *constants, size_t count);
+    ScInternalResult sc_internal_compiler_hlsl_get_shader_model(const ScInternalCompilerHlsl *compiler, int32_t *shader_model);
+    ScInternalResult sc_internal_compiler_hlsl_set_shader_model(const ScInternalCompilerHlsl *compiler, const int32_t shader_model);
 #endif
 
 #ifdef SPIRV_CROSS_WRAPPER_MSL
@@ -190,47 +218,96 @@ extern "C"
     ScInternalResult sc_internal_compiler_msl_new(ScInternalCompilerMsl **compiler, const uint32_t *ir, const size_t size);
     ScInternalResult sc_internal_compiler_msl_set_options(const ScInternalCompilerMsl *compiler, const ScMslCompilerOptions *options);
     ScInternalResult sc_internal_compiler_msl_get_is_rasterization_disabled(const ScInternalCompilerMsl *compiler, bool *is_rasterization_disabled);
+    ScInternalResult sc_internal_compiler_msl_get_spirv(const ScInternalCompilerMsl *compiler, const uint32_t **words, size_t *size);
     ScInternalResult sc_internal_compiler_msl_compile(const ScInternalCompilerBase *compiler, const char **shader,
                                                       const spirv_cross::MSLShaderInterfaceVariable *p_vat_overrides, const size_t vat_override_count,
                                                       const spirv_cross::MSLResourceBinding *p_res_overrides, const size_t res_override_count,
                                                       const ScMslConstSamplerMapping *p_const_samplers, const size_t const_sampler_count);
     ScInternalResult sc_internal_compiler_get_automatic_msl_resource_binding(const ScInternalCompilerMsl *compiler, uint32_t id, uint32_t *result);
+    ScInternalResult sc_internal_compiler_msl_set_argument_buffer_device_address_space(const ScInternalCompilerMsl *compiler, uint32_t desc_set, bool device_storage);
+    ScInternalResult sc_internal_compiler_msl_add_dynamic_buffer(const ScInternalCompilerMsl *compiler, uint32_t desc_set, uint32_t binding, uint32_t index);
+    ScInternalResult sc_internal_compiler_msl_add_inline_uniform_block(const ScInternalCompilerMsl *compiler, uint32_t desc_set, uint32_t binding);
+    ScInternalResult sc_internal_compiler_msl_set_combined_sampler_suffix(const ScInternalCompilerMsl *compiler, const char *suffix);
+    ScInternalResult sc_internal_compiler_msl_get_combined_sampler_suffix(const ScInternalCompilerMsl *compiler, const char **suffix);
+    ScInternalResult sc_internal_compiler_msl_buffer_requires_array_length(const ScInternalCompilerMsl *compiler, uint32_t id, bool *result);
+    ScInternalResult sc_internal_compiler_msl_set_enabled_fragment_outputs(const ScInternalCompilerMsl *compiler, uint32_t mask);
+    ScInternalResult sc_internal_compiler_msl_get_version(const ScInternalCompilerMsl *compiler, uint32_t *version);
+    ScInternalResult sc_internal_compiler_msl_set_version(const ScInternalCompilerMsl *compiler, const uint32_t version);
 #endif
 
 #ifdef SPIRV_CROSS_WRAPPER_GLSL
     ScInternalResult sc_internal_compiler_glsl_new(ScInternalCompilerGlsl **compiler, const uint32_t *ir, const size_t size);
     ScInternalResult sc_internal_compiler_glsl_set_options(const ScInternalCompilerGlsl *compiler, const ScGlslCompilerOptions *options);
+    ScInternalResult sc_internal_compiler_glsl_get_version(const ScInternalCompilerGlsl *compiler, uint32_t *version, bool *es);
+    ScInternalResult sc_internal_compiler_glsl_set_version(const ScInternalCompilerGlsl *compiler, const uint32_t version, const bool es);
+    ScInternalResult sc_internal_compiler_glsl_get_spirv(const ScInternalCompilerGlsl *compiler, const uint32_t **words, size_t *size);
     ScInternalResult sc_internal_compiler_glsl_build_combined_image_samplers(const ScInternalCompilerBase *compiler);
     ScInternalResult sc_internal_compiler_glsl_get_combined_image_samplers(const ScInternalCompilerBase *compiler, const ScCombinedImageSampler **samplers, size_t *size);
     ScInternalResult sc_internal_compiler_glsl_add_header_line(const ScInternalCompilerBase *compiler, const char *str);
     ScInternalResult sc_internal_compiler_glsl_flatten_buffer_block(const ScInternalCompilerBase *compiler, const uint32_t id);
+    ScInternalResult sc_internal_compiler_glsl_get_required_extensions(const ScInternalCompilerBase *compiler, const char ***extensions, size_t *size);
+#endif
+
+#ifdef SPIRV_CROSS_WRAPPER_DEBUG_IR
+    ScInternalResult sc_internal_compiler_debug_get_id(const ScInternalCompilerBase *compiler, const uint32_t id, const char **kind, uint32_t *type_id);
+    ScInternalResult sc_internal_compiler_debug_get_decorations(const ScInternalCompilerBase *compiler, const uint32_t id, spv::Decoration **decorations, size_t *size);
+    ScInternalResult sc_internal_compiler_debug_get_member_decorations(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, spv::Decoration **decorations, size_t *size);
 #endif
 
     ScInternalResult sc_internal_compiler_get_decoration(const ScInternalCompilerBase *compiler, uint32_t *result, const uint32_t id, const spv::Decoration decoration);
+    ScInternalResult sc_internal_compiler_has_decoration(const ScInternalCompilerBase *compiler, bool *result, const uint32_t id, const spv::Decoration decoration);
+    ScInternalResult sc_internal_compiler_get_binary_offset_for_decoration(const ScInternalCompilerBase *compiler, bool *result, uint32_t *word_offset, const uint32_t id, const spv::Decoration decoration);
     ScInternalResult sc_internal_compiler_set_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const spv::Decoration decoration, const uint32_t argument);
+    ScInternalResult sc_internal_compiler_get_decoration_string(const ScInternalCompilerBase *compiler, const char **result, const uint32_t id, const spv::Decoration decoration);
+    ScInternalResult sc_internal_compiler_get_member_decoration_string(const ScInternalCompilerBase *compiler, const char **result, const uint32_t id, const uint32_t index, const spv::Decoration decoration);
+    ScInternalResult sc_internal_compiler_set_decoration_string(const ScInternalCompilerBase *compiler, const uint32_t id, const spv::Decoration decoration, const char *argument);
     ScInternalResult sc_internal_compiler_unset_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const spv::Decoration decoration);
     ScInternalResult sc_internal_compiler_get_name(const ScInternalCompilerBase *compiler, const uint32_t id, const char **name);
     ScInternalResult sc_internal_compiler_set_name(const ScInternalCompilerBase *compiler, const uint32_t id, const char *name);
     ScInternalResult sc_internal_compiler_set_member_name(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const char *name);
+    ScInternalResult sc_internal_compiler_strip_debug_info(const ScInternalCompilerBase *compiler);
     ScInternalResult sc_internal_compiler_get_entry_points(const ScInternalCompilerBase *compiler, ScEntryPoint **entry_points, size_t *size);
     ScInternalResult sc_internal_compiler_get_active_buffer_ranges(const ScInternalCompilerBase *compiler, uint32_t id, ScBufferRange **active_buffer_ranges, size_t *size);
+    ScInternalResult sc_internal_compiler_get_buffer_block_decorations(const ScInternalCompilerBase *compiler, uint32_t id, spv::Decoration **decorations, size_t *size);
     ScInternalResult sc_internal_compiler_get_cleansed_entry_point_name(const ScInternalCompilerBase *compiler, const char *original_entry_point_name, const spv::ExecutionModel execution_model, const char **compiled_entry_point_name);
+    ScInternalResult sc_internal_compiler_get_remapped_declared_block_name(const ScInternalCompilerBase *compiler, const uint32_t id, const char **name);
+    ScInternalResult sc_internal_compiler_get_source_file_name(const ScInternalCompilerBase *compiler, const char **name);
     ScInternalResult sc_internal_compiler_get_shader_resources(const ScInternalCompilerBase *compiler, ScShaderResources *shader_resources);
+    ScInternalResult sc_internal_compiler_get_shader_resources_for_active_variables(const ScInternalCompilerBase *compiler, ScShaderResources *shader_resources, const uint32_t *ids, const size_t size);
     ScInternalResult sc_internal_compiler_get_specialization_constants(const ScInternalCompilerBase *compiler, ScSpecializationConstant **constants, size_t *size);
     // `uint64_t` isn't supported in Emscripten without implicitly splitting the value into two `uint32_t` - instead do it explicitly
     ScInternalResult sc_internal_compiler_set_scalar_constant(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t constant_high_bits, const uint32_t constant_low_bits);
+    ScInternalResult sc_internal_compiler_get_constants(const ScInternalCompilerBase *compiler, uint32_t **ids, size_t *size);
+    ScInternalResult sc_internal_compiler_get_ids_for_type(const ScInternalCompilerBase *compiler, const uint32_t type, uint32_t **ids, size_t *size);
+    ScInternalResult sc_internal_compiler_set_composite_constant(const ScInternalCompilerBase *compiler, const uint32_t id, const uint64_t *values, const size_t size, size_t *component_count);
+    ScInternalResult sc_internal_compiler_get_scalar_constant(const ScInternalCompilerBase *compiler, const uint32_t id, bool *is_scalar, uint32_t *constant_high_bits, uint32_t *constant_low_bits);
     ScInternalResult sc_internal_compiler_get_type(const ScInternalCompilerBase *compiler, const uint32_t id, const ScType **spirv_type);
+    ScInternalResult sc_internal_compiler_get_variable_type_id(const ScInternalCompilerBase *compiler, const uint32_t id, uint32_t *type_id);
     ScInternalResult sc_internal_compiler_get_member_name(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const char **name);
     ScInternalResult sc_internal_compiler_get_member_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const spv::Decoration decoration, uint32_t *result);
     ScInternalResult sc_internal_compiler_set_member_decoration(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, const spv::Decoration decoration, const uint32_t argument);
+    ScInternalResult sc_internal_compiler_get_member_count(const ScInternalCompilerBase *compiler, const uint32_t id, bool *is_struct, uint32_t *count);
     ScInternalResult sc_internal_compiler_get_declared_struct_size(const ScInternalCompilerBase *compiler, const uint32_t id, uint32_t *result);
+    ScInternalResult sc_internal_compiler_get_declared_struct_size_runtime_array(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t array_size, uint32_t *result);
     ScInternalResult sc_internal_compiler_get_declared_struct_member_size(const ScInternalCompilerBase *compiler, const uint32_t id, const uint32_t index, uint32_t *result);
+    ScInternalResult sc_internal_compiler_get_id_bound(const ScInternalCompilerBase *compiler, uint32_t *result);
     ScInternalResult sc_internal_compiler_rename_interface_variable(const ScInternalCompilerBase *compiler, const ScResource *resources, const size_t resources_size, uint32_t location, const char *name);
+    ScInternalResult sc_internal_compiler_set_interface_variable_name(const ScInternalCompilerBase *compiler, uint32_t id, uint32_t location, const char *name);
     ScInternalResult sc_internal_compiler_get_work_group_size_specialization_constants(const ScInternalCompilerBase *compiler, ScSpecializationConstant **constants);
     ScInternalResult sc_internal_compiler_set_entry_point(const ScInternalCompilerBase *compiler, const char *name, const spv::ExecutionModel execution_model);
+    ScInternalResult sc_internal_compiler_set_common_options(const ScInternalCompilerBase *compiler, const ScCommonOptions *options);
+    ScInternalResult sc_internal_compiler_get_execution_model(const ScInternalCompilerBase *compiler, spv::ExecutionModel *execution_model);
+    ScInternalResult sc_internal_compiler_get_entry_point_name(const ScInternalCompilerBase *compiler, const char **name);
+    ScInternalResult sc_internal_compiler_get_declared_capabilities(const ScInternalCompilerBase *compiler, spv::Capability **capabilities, size_t *size);
+    ScInternalResult sc_internal_compiler_get_addressing_model(const ScInternalCompilerBase *compiler, spv::AddressingModel *addressing_model);
+    ScInternalResult sc_internal_compiler_has_execution_mode(const ScInternalCompilerBase *compiler, const spv::ExecutionMode mode, bool *result);
+    ScInternalResult sc_internal_compiler_has_active_builtin(const ScInternalCompilerBase *compiler, const spv::BuiltIn builtin, const spv::StorageClass storage, bool *result);
+    ScInternalResult sc_internal_compiler_get_execution_mode_argument(const ScInternalCompilerBase *compiler, const spv::ExecutionMode mode, const uint32_t index, uint32_t *result);
     ScInternalResult sc_internal_compiler_compile(const ScInternalCompilerBase *compiler, const char **shader);
     ScInternalResult sc_internal_compiler_delete(ScInternalCompilerBase *compiler);
+    ScInternalResult sc_internal_compiler_get_variable_storage_class(const ScInternalCompilerBase *compiler, uint32_t id, spv::StorageClass *storage_class);
     ScInternalResult sc_internal_compiler_get_active_interface_variables(const ScInternalCompilerBase *compiler, uint32_t **ids, size_t* size);
+    ScInternalResult sc_internal_compiler_set_enabled_interface_variables(const ScInternalCompilerBase *compiler, const uint32_t *ids, const size_t size);
 
     ScInternalResult sc_internal_free_pointer(void *pointer);
 }
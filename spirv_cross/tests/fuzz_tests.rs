@@ -0,0 +1,37 @@
+use spirv_cross::{hlsl as lang, spirv};
+
+mod common;
+use crate::common::words_from_bytes;
+
+/// A small xorshift generator, so runs are reproducible without extra dependencies.
+struct XorShift(u32);
+
+impl XorShift {
+    fn next(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+}
+
+#[test]
+fn parse_rejects_random_words_without_panicking() {
+    let mut rng = XorShift(0x2545_f491);
+    for _ in 0..1024 {
+        let len = (rng.next() % 64) as usize;
+        let words = (0..len).map(|_| rng.next()).collect::<Vec<_>>();
+        let module = spirv::Module::from_words(&words);
+        assert!(spirv::Ast::<lang::Target>::parse(&module).is_err());
+    }
+}
+
+#[test]
+fn parse_rejects_truncated_header() {
+    let words = words_from_bytes(include_bytes!("shaders/simple.vert.spv"));
+    for len in 0..5 {
+        let module = spirv::Module::from_words(&words[..len]);
+        assert!(spirv::Ast::<lang::Target>::parse(&module).is_err());
+    }
+}
+
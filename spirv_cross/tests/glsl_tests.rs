@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::rc::Rc;
 use spirv_cross::{glsl, spirv};
 
 mod common;
@@ -9,6 +10,11 @@ fn glsl_compiler_options_has_default() {
     let compiler_options = glsl::CompilerOptions::default();
     assert_eq!(compiler_options.vertex.invert_y, false);
     assert_eq!(compiler_options.vertex.transform_clip_space, false);
+    assert!(!compiler_options.force_flattened_io_blocks);
+    assert!(compiler_options.keep_unnamed_blocks);
+    assert!(!compiler_options.annotate_ids);
+    assert_eq!(compiler_options.common, spirv::CommonOptions::default());
+    assert!(compiler_options.common.enable_row_major_load_workaround);
 }
 
 #[test]
@@ -47,6 +53,168 @@ void main()
     );
 }
 
+#[test]
+fn ast_applies_common_options() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V3_00Es;
+    options.common.ovr_multiview_view_count = 2;
+    ast.set_compiler_options(&options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("#extension GL_OVR_multiview2 : require"));
+    assert!(shader.contains("layout(num_views = 2) in;"));
+}
+
+#[test]
+fn ast_annotates_ids() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.annotate_ids = true;
+    ast.set_compiler_options(&options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("} _22 /* id=22 */;"));
+    let shader_resources = ast.get_shader_resources().unwrap();
+    for resource in shader_resources
+        .stage_inputs
+        .iter()
+        .chain(&shader_resources.stage_outputs)
+    {
+        let declaration = format!(" {} /* id={} */;", resource.name, resource.id);
+        assert_eq!(shader.matches(&declaration).count(), 1);
+    }
+    assert!(shader.contains("v_normal = a_normal;"));
+}
+
+#[test]
+fn ast_annotates_ids_only_on_declarations() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/shadowed_names.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.annotate_ids = true;
+    ast.set_compiler_options(&options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    for id in ast.ids_for_type(spirv::IdType::Variable).unwrap() {
+        let name = ast.get_name(id).unwrap();
+        let annotation = format!("{} /* id={} */", name, id);
+        // Only global variables are annotated, not the local `result` in `main`.
+        let expected_count = if name == "result" { 0 } else { 1 };
+        assert_eq!(shader.matches(&annotation).count(), expected_count);
+    }
+
+    // `color` is a prefix of `color_scale`, which is declared first.
+    assert!(shader.contains("out vec4 color_scale /* id="));
+    // The parameter and return of `passthrough` share the name of the local in `main`.
+    assert!(shader.contains("float passthrough(float result)"));
+    assert!(shader.contains("    return result;\n"));
+}
+
+#[test]
+fn ast_annotates_ids_on_top_level_declarations() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/member_name_clash.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.annotate_ids = true;
+    ast.set_compiler_options(&options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    let shader_resources = ast.get_shader_resources().unwrap();
+    let params_id = shader_resources.uniform_buffers[0].id;
+    let color_id = shader_resources.stage_outputs[0].id;
+    // The `color` member of the block is declared before the output of the same name.
+    assert!(shader.contains("    vec4 color;\n"));
+    assert!(shader.contains(&format!("}} params /* id={} */;", params_id)));
+    assert!(shader.contains(&format!("out vec4 color /* id={} */;", color_id)));
+}
+
+#[test]
+fn ast_compiles_cached() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut options = glsl::CompilerOptions::default();
+
+    let shader = ast.compile_cached(&options).unwrap();
+    assert!(Rc::ptr_eq(&shader, &ast.compile_cached(&options).unwrap()));
+    assert_eq!(*shader, ast.compile().unwrap());
+
+    options.version = glsl::Version::V3_30;
+    let older_shader = ast.compile_cached(&options).unwrap();
+    assert!(older_shader.starts_with("#version 330\n"));
+    assert!(!Rc::ptr_eq(&shader, &older_shader));
+
+    ast.clear_compile_cache();
+    let recompiled_shader = ast.compile_cached(&options).unwrap();
+    assert!(!Rc::ptr_eq(&older_shader, &recompiled_shader));
+    assert_eq!(older_shader, recompiled_shader);
+
+    // A cache hit still applies the options.
+    let shader = ast
+        .compile_cached(&glsl::CompilerOptions::default())
+        .unwrap();
+    let hit = ast.compile_cached(&options).unwrap();
+    assert!(Rc::ptr_eq(&recompiled_shader, &hit));
+    assert_eq!(*hit, ast.compile().unwrap());
+    assert_ne!(*shader, *hit);
+}
+
+#[test]
+fn ast_compile_cache_is_cleared_by_changes() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let options = glsl::CompilerOptions::default();
+
+    let shader = ast.compile_cached(&options).unwrap();
+    let id = ast.get_shader_resources().unwrap().stage_inputs[0].id;
+    let name = ast.get_name(id).unwrap();
+    ast.set_name(id, "renamed").unwrap();
+
+    let renamed_shader = ast.compile_cached(&options).unwrap();
+    assert!(!Rc::ptr_eq(&shader, &renamed_shader));
+    assert!(shader.contains(&name));
+    assert!(renamed_shader.contains(" renamed;"));
+    assert!(!renamed_shader.contains(&name));
+}
+
+#[test]
+fn ast_compiles_to_bytes() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert_eq!(ast.compile_bytes().unwrap(), shader.into_bytes());
+}
+
+#[test]
+fn ast_parses_with_options() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V3_30;
+    options.enable_420_pack_extension = false;
+
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&options).unwrap();
+    let mut ast_with_options =
+        spirv::Ast::<glsl::Target>::parse_with_options(&module, &options).unwrap();
+
+    let expected = ast.compile().unwrap();
+    assert!(expected.starts_with("#version 330\n"));
+    assert_eq!(ast_with_options.compile().unwrap(), expected);
+}
+
 #[test]
 fn ast_compiles_all_versions_to_glsl() {
     use spirv_cross::glsl::Version::*;
@@ -70,6 +238,101 @@ fn ast_compiles_all_versions_to_glsl() {
 }
 
 
+#[test]
+fn ast_compiles_with_webgl_presets() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&glsl::CompilerOptions::webgl2()).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.starts_with("#version 300 es\n"));
+    assert!(shader.contains("uniform uniform_buffer_object\n{"));
+
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&glsl::CompilerOptions::webgl1()).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.starts_with("#version 100\n"));
+    assert!(shader.contains("struct uniform_buffer_object"));
+    assert!(!shader.contains("uniform uniform_buffer_object\n{"));
+    assert!(!shader.contains("layout("));
+}
+
+#[test]
+fn ast_rejects_shaders_unsupported_by_webgl_presets() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/uniform_buffer_array.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&glsl::CompilerOptions::webgl2()).unwrap();
+    ast.compile().unwrap();
+    ast.set_compiler_options(&glsl::CompilerOptions::webgl1()).unwrap();
+    assert_eq!(
+        ast.compile(),
+        Err(spirv_cross::ErrorCode::CompilationError(String::from(
+            "uniform buffer array `params` is not supported by GLSL ES 1.00"
+        )))
+    );
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/dynamic_texture_index.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&glsl::CompilerOptions::default())
+        .unwrap();
+    ast.compile().unwrap();
+    ast.set_compiler_options(&glsl::CompilerOptions::webgl2()).unwrap();
+    match ast.compile() {
+        Err(spirv_cross::ErrorCode::CompilationError(message)) => {
+            assert!(message.starts_with("sampler array `u_textures` is indexed"))
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/texture_fixed_array.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&glsl::CompilerOptions::webgl2()).unwrap();
+    ast.compile().unwrap();
+}
+
+#[test]
+fn ast_compiles_to_version() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V4_60;
+    ast.set_compiler_options(&options).unwrap();
+
+    let shader = ast.compile_version(glsl::Version::V3_00Es).unwrap();
+    assert!(shader.starts_with("#version 300 es\n"));
+    let shader = ast.compile_version(glsl::Version::V3_30).unwrap();
+    assert!(shader.starts_with("#version 330\n"));
+    assert!(ast.compile().unwrap().starts_with("#version 460\n"));
+}
+
+#[test]
+fn emits_line_directives() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/line_directives.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+
+    ast.set_compiler_options(&glsl::CompilerOptions::default())
+        .unwrap();
+    assert!(!ast.compile().unwrap().contains("#line"));
+
+    let mut options = glsl::CompilerOptions::default();
+    options.emit_line_directives = true;
+    ast.set_compiler_options(&options).unwrap();
+    assert!(ast
+        .compile()
+        .unwrap()
+        .contains("#line 7 \"line_directives.frag\""));
+}
+
 #[test]
 fn ast_active_variables() {
     let vert =
@@ -181,6 +444,82 @@ void main()
     );
 }
 
+#[test]
+fn ast_sets_interface_variable_name() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/struct.vert.spv")));
+    let mut options = glsl::CompilerOptions::default();
+    options.version = glsl::Version::V1_00Es;
+
+    let mut bulk_ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    bulk_ast.set_compiler_options(&options).unwrap();
+    let stage_outputs = bulk_ast.get_shader_resources().unwrap().stage_outputs;
+    bulk_ast
+        .rename_interface_variable(&stage_outputs, 0, "renamed")
+        .unwrap();
+
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&options).unwrap();
+    assert!(ast
+        .set_interface_variable_name(stage_outputs[0].id, 1, "renamed")
+        .is_err());
+    ast.set_interface_variable_name(stage_outputs[0].id, 0, "renamed")
+        .unwrap();
+
+    assert_eq!(ast.compile().unwrap(), bulk_ast.compile().unwrap());
+}
+
+#[test]
+fn ast_gets_combined_image_sampler_comparison() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/shadow.asm.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+
+    let separate_images = ast.get_shader_resources().unwrap().separate_images;
+    let image_id = |name: &str| {
+        separate_images
+            .iter()
+            .find(|resource| resource.name == name)
+            .unwrap()
+            .id
+    };
+    let shadow_map = image_id("shadow_map");
+    let albedo = image_id("albedo");
+
+    let combined_image_samplers = ast.get_combined_image_samplers().unwrap();
+    assert_eq!(combined_image_samplers.len(), 2);
+    for cis in combined_image_samplers {
+        assert_eq!(cis.comparison, cis.image_id == shadow_map);
+        assert!(cis.image_id == shadow_map || cis.image_id == albedo);
+    }
+}
+
+#[test]
+fn ast_gets_cached_types_after_compiling() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut cached_ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    let type_ids = cached_ast.ids_for_type(spirv::IdType::Type).unwrap();
+    for &id in &type_ids {
+        cached_ast.get_type(id).unwrap();
+    }
+    cached_ast.compile().unwrap();
+
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.compile().unwrap();
+
+    // Compiling builds combined image samplers, which adds new types.
+    let compiled_type_ids = ast.ids_for_type(spirv::IdType::Type).unwrap();
+    assert!(compiled_type_ids.len() > type_ids.len());
+    assert_eq!(
+        cached_ast.ids_for_type(spirv::IdType::Type).unwrap(),
+        compiled_type_ids
+    );
+    for id in compiled_type_ids {
+        assert_eq!(cached_ast.get_type(id).unwrap(), ast.get_type(id).unwrap());
+    }
+}
+
 #[test]
 fn ast_can_rename_combined_image_samplers() {
     let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
@@ -255,6 +594,52 @@ void main()
     );
 }
 
+#[test]
+fn forces_member_name() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+
+    let id = ast.get_shader_resources().unwrap().uniform_buffers[0].base_type_id;
+    assert!(ast.force_member_name(id, 1, "_m1").is_err());
+    assert!(ast.force_member_name(id, 1, "spv_scale").is_err());
+    assert!(ast.force_member_name(id, 1, "gl_scale").is_err());
+    assert!(ast.force_member_name(id, 1, "input").is_err());
+    assert!(ast.force_member_name(id, 1, "sample").is_err());
+    assert!(ast.force_member_name(id, 1, "filter").is_err());
+    assert!(ast.force_member_name(id, 1, "vec4").is_err());
+    assert!(ast.force_member_name(id, 1, "usampler2DArray").is_err());
+    assert!(ast.force_member_name(id, 1, "a__b").is_err());
+
+    ast.force_member_name(id, 1, "scale").unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("    float scale;\n"));
+    assert!(shader.contains("* _22.scale;"));
+}
+
+#[test]
+fn names_unnamed_blocks() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("} _22;"));
+
+    let mut options = glsl::CompilerOptions::default();
+    options.keep_unnamed_blocks = false;
+    ast.set_compiler_options(&options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("} uniform_buffer_object_instance;"));
+    assert!(shader.contains("* uniform_buffer_object_instance.u_scale;"));
+    assert_eq!(ast.get_name(22).unwrap(), "");
+
+    options.keep_unnamed_blocks = true;
+    ast.set_compiler_options(&options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("} _22;"));
+}
+
 #[test]
 fn add_header_line() {
     let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
@@ -411,3 +796,116 @@ void main()
         assert_eq!(&ast.compile().unwrap(), expected_result);
     }
 }
+
+#[test]
+fn applies_clip_space_options() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+
+    let mut compiler_options = glsl::CompilerOptions::default();
+    compiler_options.clip_space = Some(spirv::ClipSpaceOptions {
+        flip_y: true,
+        zero_to_one_depth: true,
+    });
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("gl_Position.y = -gl_Position.y;"));
+    assert!(shader.contains("gl_Position.z = 2.0 * gl_Position.z - gl_Position.w;"));
+
+    compiler_options.clip_space = Some(spirv::ClipSpaceOptions {
+        flip_y: false,
+        zero_to_one_depth: false,
+    });
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(!shader.contains("gl_Position.y = -"));
+    assert!(!shader.contains("gl_Position.z ="));
+}
+
+#[test]
+fn ast_compiles_all_entry_points() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/vs_and_fs.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&glsl::CompilerOptions::default())
+        .unwrap();
+    let default_shader = ast.compile().unwrap();
+
+    let results = ast.compile_all_entry_points().unwrap();
+    assert_eq!(results.len(), 2);
+
+    let (vs_name, vs_execution_model, vs_source) = &results[0];
+    assert_eq!(vs_name, "main_vs");
+    assert_eq!(*vs_execution_model, spirv::ExecutionModel::Vertex);
+    assert!(vs_source.contains("gl_Position = vec4(1.0);"));
+    assert!(!vs_source.contains("color"));
+
+    let (fs_name, fs_execution_model, fs_source) = &results[1];
+    assert_eq!(fs_name, "main_fs");
+    assert_eq!(*fs_execution_model, spirv::ExecutionModel::Fragment);
+    assert!(fs_source.contains("color = vec4(1.0);"));
+    assert!(!fs_source.contains("gl_Position"));
+
+    assert_ne!(vs_source, fs_source);
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("gl_Position = vec4(1.0);"));
+    assert_eq!(shader, default_shader);
+
+    // The interface variables enabled by the caller are restored
+    let active_variables = ast.get_active_interface_variables().unwrap();
+    ast.set_enabled_interface_variables(&active_variables)
+        .unwrap();
+    let pruned_shader = ast.compile().unwrap();
+    ast.compile_all_entry_points().unwrap();
+    assert_eq!(ast.compile().unwrap(), pruned_shader);
+}
+
+#[test]
+fn ast_strips_debug_info() {
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&spirv::Module::from_words(words_from_bytes(
+        include_bytes!("shaders/simple.vert.spv"),
+    )))
+    .unwrap();
+    ast.set_compiler_options(&glsl::CompilerOptions::default())
+        .unwrap();
+    ast.strip_debug_info().unwrap();
+
+    let shader = ast.compile().unwrap();
+    for name in &[
+        "uniform_buffer_object",
+        "u_model_view_projection",
+        "u_scale",
+        "v_normal",
+        "a_normal",
+        "a_position",
+    ] {
+        assert!(!shader.contains(name), "`{}` was not stripped", name);
+    }
+}
+
+#[test]
+fn ast_gets_required_extensions() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/physical_storage_buffer.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    assert!(matches!(
+        ast.get_required_extensions(),
+        Err(spirv_cross::ErrorCode::CompilationError(_))
+    ));
+
+    ast.compile().unwrap();
+    assert!(ast
+        .get_required_extensions()
+        .unwrap()
+        .contains(&String::from("GL_EXT_buffer_reference")));
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<glsl::Target>::parse(&module).unwrap();
+    ast.compile().unwrap();
+    assert!(ast.get_required_extensions().unwrap().is_empty());
+}
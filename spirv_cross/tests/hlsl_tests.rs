@@ -11,6 +11,12 @@ fn hlsl_compiler_options_has_default() {
     assert_eq!(compiler_options.point_coord_compat, false);
     assert_eq!(compiler_options.vertex.invert_y, false);
     assert_eq!(compiler_options.vertex.transform_clip_space, false);
+    assert!(!compiler_options.force_storage_buffer_as_uav);
+    assert!(!compiler_options.flatten_matrix_vertex_input_semantics);
+    assert!(!compiler_options.preserve_structured_buffers);
+    assert!(!compiler_options.annotate_ids);
+    assert_eq!(compiler_options.common, spirv::CommonOptions::default());
+    assert!(compiler_options.common.enable_row_major_load_workaround);
 }
 
 #[test]
@@ -93,6 +99,24 @@ fn ast_compiles_all_shader_models_to_hlsl() {
     }
 }
 
+#[test]
+fn ast_compiles_storage_buffer_as_uav() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/runtime_array.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+
+    let mut options = hlsl::CompilerOptions::default();
+    options.shader_model = hlsl::ShaderModel::V5_0;
+    options.force_storage_buffer_as_uav = true;
+    ast.set_compiler_options(&options).unwrap();
+
+    assert!(ast
+        .compile()
+        .unwrap()
+        .contains("RWByteAddressBuffer data : register(u0);"));
+}
+
 #[test]
 fn forces_zero_initialization() {
     let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
@@ -285,3 +309,49 @@ SPIRV_Cross_Output main()
         assert_eq!(&ast.compile().unwrap(), expected_result);
     }
 }
+
+#[test]
+fn gets_stage_output_struct_name() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&hlsl::CompilerOptions::default())
+        .unwrap();
+
+    assert!(ast.get_stage_output_struct_name().is_err());
+
+    let shader = ast.compile().unwrap();
+    let name = ast.get_stage_output_struct_name().unwrap();
+    assert_eq!(name, "SPIRV_Cross_Output");
+    assert!(shader.contains(&format!("struct {}\n", name)));
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/workgroup.comp.spv"
+    )));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    ast.compile().unwrap();
+
+    assert!(ast.get_stage_output_struct_name().is_err());
+}
+
+#[test]
+fn gets_push_constant_block_name() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/push_constant.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+
+    assert!(ast.get_push_constant_block_name().is_err());
+
+    let shader = ast.compile().unwrap();
+    let name = ast.get_push_constant_block_name().unwrap();
+    assert!(!name.is_empty());
+    assert!(shader.contains(&name));
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<hlsl::Target>::parse(&module).unwrap();
+    ast.compile().unwrap();
+
+    assert!(ast.get_push_constant_block_name().is_err());
+}
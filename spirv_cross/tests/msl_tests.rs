@@ -12,6 +12,19 @@ fn msl_compiler_options_has_default() {
     assert_eq!(compiler_options.vertex.transform_clip_space, false);
     assert!(compiler_options.resource_binding_overrides.is_empty());
     assert!(compiler_options.vertex_attribute_overrides.is_empty());
+    assert!(!compiler_options.multiview);
+    assert!(compiler_options.multiview_layered_rendering);
+    assert!(!compiler_options.sample_dref_lod_array_as_grad);
+    assert!(!compiler_options.ios_support_base_vertex_instance);
+    assert_eq!(compiler_options.array_emission, msl::ArrayEmission::Auto);
+    assert!(!compiler_options.emulate_subgroups);
+    assert_eq!(compiler_options.fixed_subgroup_size, 0);
+    assert_eq!(compiler_options.automatic_binding_base, 0);
+    assert!(!compiler_options.annotate_ids);
+    assert_eq!(compiler_options.common, spirv::CommonOptions::default());
+    assert!(compiler_options.common.enable_row_major_load_workaround);
+    assert!(!compiler_options.emulate_cube_array);
+    assert_eq!(compiler_options.r32ui_linear_texture_alignment, 4);
 }
 
 #[test]
@@ -35,6 +48,119 @@ fn is_rasterization_enabled() {
     }
 }
 
+#[test]
+fn gets_rasterization_state() {
+    let modules = [
+        (
+            msl::RasterState::Enabled,
+            spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv"))),
+        ),
+        (
+            msl::RasterState::Disabled,
+            spirv::Module::from_words(words_from_bytes(include_bytes!(
+                "shaders/rasterize_disabled.vert.spv"
+            ))),
+        ),
+        // The position is written whatever the value of the specialization constant
+        (
+            msl::RasterState::Enabled,
+            spirv::Module::from_words(words_from_bytes(include_bytes!(
+                "shaders/rasterize_spec_constant_both_arms.asm.spv"
+            ))),
+        ),
+        // A `discard` does not decide whether the pipeline rasterizes
+        (
+            msl::RasterState::Enabled,
+            spirv::Module::from_words(words_from_bytes(include_bytes!(
+                "shaders/discard_spec_constant.asm.spv"
+            ))),
+        ),
+    ];
+    for (expected, module) in &modules {
+        let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+        assert!(ast.rasterization_state().is_err());
+        ast.compile().unwrap();
+        assert_eq!(*expected, ast.rasterization_state().unwrap());
+    }
+}
+
+#[test]
+fn gets_rasterization_state_depending_on_spec_constant() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/rasterize_spec_constant.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let spec_constant = ast.get_specialization_constants().unwrap()[0];
+    assert_eq!(spec_constant.constant_id, 0);
+
+    ast.compile().unwrap();
+    assert_eq!(
+        ast.rasterization_state().unwrap(),
+        msl::RasterState::DependsOnSpecConstant(spec_constant.id)
+    );
+}
+
+#[test]
+fn gets_buffer_size_buffer_layout() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/runtime_array.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&msl::CompilerOptions::default())
+        .unwrap();
+    assert!(ast.get_buffer_size_buffer_layout().is_err());
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("spvBufferSizeConstants"));
+    assert_eq!(ast.get_buffer_size_buffer_layout().unwrap(), vec![(0, 0)]);
+}
+
+#[test]
+fn gets_buffer_size_buffer_layout_with_overrides() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/runtime_array.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.resource_binding_overrides.insert(
+        msl::ResourceBindingLocation {
+            stage: spirv::ExecutionModel::GlCompute,
+            desc_set: 0,
+            binding: 0,
+        },
+        msl::ResourceBinding {
+            buffer_id: 3,
+            ..msl::ResourceBinding::array(0)
+        },
+    );
+    ast.set_compiler_options(&compiler_options).unwrap();
+    ast.compile().unwrap();
+    assert_eq!(ast.get_buffer_size_buffer_layout().unwrap(), vec![(3, 12)]);
+
+    compiler_options.resource_binding_overrides.clear();
+    compiler_options.automatic_binding_base = 2;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    ast.compile().unwrap();
+    assert_eq!(ast.get_buffer_size_buffer_layout().unwrap(), vec![(2, 8)]);
+}
+
+#[test]
+fn ast_rejects_reserved_msl_member_names() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+
+    let id = ast.get_shader_resources().unwrap().uniform_buffers[0].base_type_id;
+    assert!(ast.set_member_name_checked(id, 1, "kernel").is_err());
+    assert!(ast.set_member_name_checked(id, 1, "device").is_err());
+    assert!(ast.set_member_name_checked(id, 1, "float3").is_err());
+    assert!(ast.set_member_name_checked(id, 1, "packed_float3").is_err());
+    assert!(ast.set_member_name_checked(id, 1, "texture2d").is_err());
+
+    ast.set_member_name_checked(id, 1, "scale").unwrap();
+    assert!(ast.compile().unwrap().contains("    float scale;\n"));
+}
+
 #[test]
 fn ast_compiles_to_msl() {
     let module =
@@ -413,6 +539,198 @@ vertex main0_out main0(uint gl_VertexIndex [[vertex_id]])
     }
 }
 
+#[test]
+fn sets_array_emission() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/const_array.vert.spv")));
+
+    let cases = [
+        (msl::ArrayEmission::Native, false, false),
+        (msl::ArrayEmission::Templated, true, true),
+        (msl::ArrayEmission::Auto, false, true),
+        (msl::ArrayEmission::Auto, true, false),
+    ];
+    for &(array_emission, force_native_arrays, templated) in cases.iter() {
+        let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+        let mut compiler_options = msl::CompilerOptions::default();
+        compiler_options.array_emission = array_emission;
+        compiler_options.force_native_arrays = force_native_arrays;
+        ast.set_compiler_options(&compiler_options).unwrap();
+        assert_eq!(ast.compile().unwrap().contains("spvUnsafeArray"), templated);
+    }
+}
+
+#[test]
+fn configures_subgroup_size() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/subgroup.asm.spv")));
+
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.version = msl::Version::V2_0;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast.compile().unwrap().contains("[[threads_per_simdgroup]]"));
+
+    compiler_options.emulate_subgroups = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("gl_SubgroupSize = 1;"));
+    assert!(!shader.contains("[[threads_per_simdgroup]]"));
+
+    compiler_options.emulate_subgroups = false;
+    compiler_options.fixed_subgroup_size = 32;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("gl_SubgroupSize = 32;"));
+    assert!(!shader.contains("[[threads_per_simdgroup]]"));
+}
+
+#[test]
+fn emulates_cube_arrays() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/cube_array.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    assert!(ast
+        .compile()
+        .unwrap()
+        .contains("texturecube_array<float> environment"));
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.platform = msl::Platform::iOS;
+    compiler_options.emulate_cube_array = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("texture2d_array<float> environment"));
+    assert!(shader.contains("spvCubemapTo2DArrayFace"));
+}
+
+#[test]
+fn emulates_image_atomics() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/image_atomic.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.platform = msl::Platform::iOS;
+    compiler_options.version = msl::Version::V2_0;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("device atomic_uint* counters_atomic"));
+    assert!(shader.contains("atomic_fetch_add_explicit(&counters_atomic["));
+}
+
+#[test]
+fn offsets_automatic_binding_indices() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.automatic_binding_base = 4;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("texture2d<float> u_texture [[texture(4)]]"));
+    assert!(shader.contains("sampler u_sampler [[sampler(4)]]"));
+
+    compiler_options.resource_binding_overrides.insert(
+        msl::ResourceBindingLocation {
+            stage: spirv::ExecutionModel::Fragment,
+            desc_set: 0,
+            binding: 1,
+        },
+        msl::ResourceBinding {
+            sampler_id: 1,
+            ..msl::ResourceBinding::array(0)
+        },
+    );
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("texture2d<float> u_texture [[texture(4)]]"));
+    assert!(shader.contains("sampler u_sampler [[sampler(1)]]"));
+}
+
+#[test]
+fn skips_explicit_indices_when_assigning_automatic_binding_indices() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/shadow.asm.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+
+    // `albedo` is given the first automatic texture index, so `shadow_map` takes the next one
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.automatic_binding_base = 4;
+    compiler_options.resource_binding_overrides.insert(
+        msl::ResourceBindingLocation {
+            stage: spirv::ExecutionModel::Fragment,
+            desc_set: 0,
+            binding: 1,
+        },
+        msl::ResourceBinding {
+            texture_id: 4,
+            ..msl::ResourceBinding::array(0)
+        },
+    );
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains(" albedo [[texture(4)]]"));
+    assert!(shader.contains(" shadow_map [[texture(5)]]"));
+    assert!(shader.contains(" linear_sampler [[sampler(4)]]"));
+}
+
+#[test]
+fn gets_tile_resources() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/subpass_input.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_tile_resources().unwrap(),
+        msl::TileResources::default()
+    );
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.platform = msl::Platform::iOS;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let tile_resources = ast.get_tile_resources().unwrap();
+    assert_eq!(tile_resources.inputs.len(), 1);
+    assert_eq!(tile_resources.inputs[0].resource.name, "color_input");
+    assert_eq!(tile_resources.inputs[0].color_attachment, 1);
+    assert!(tile_resources.threadgroup_variables.is_empty());
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/threadgroup.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let tile_resources = ast.get_tile_resources().unwrap();
+    assert!(tile_resources.inputs.is_empty());
+    assert_eq!(tile_resources.threadgroup_variables, [4]);
+    assert_eq!(ast.get_name(4).unwrap(), "scratch");
+}
+
+#[test]
+fn applies_clip_space_options() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.clip_space = Some(spirv::ClipSpaceOptions {
+        flip_y: true,
+        zero_to_one_depth: false,
+    });
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("out.gl_Position.y = -"));
+    assert!(shader.contains("out.gl_Position.z = (out.gl_Position.z + out.gl_Position.w) * 0.5;"));
+
+    compiler_options.clip_space = Some(spirv::ClipSpaceOptions::default());
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(!shader.contains("out.gl_Position.y = -"));
+    assert!(!shader.contains("out.gl_Position.z ="));
+}
+
 #[test]
 fn forces_zero_initialization() {
     let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
@@ -577,3 +895,287 @@ fragment main_fs_out main_fs()
         assert_eq!(&ast.compile().unwrap(), expected_result);
     }
 }
+
+#[test]
+fn ast_sets_combined_sampler_suffix() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+
+    assert_eq!(ast.get_combined_sampler_suffix().unwrap(), "Smplr");
+    ast.set_combined_sampler_suffix("CombinedSampler").unwrap();
+    assert_eq!(
+        ast.get_combined_sampler_suffix().unwrap(),
+        "CombinedSampler"
+    );
+}
+
+#[test]
+fn ast_gets_compiler_options() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    assert_eq!(ast.get_compiler_options(), msl::CompilerOptions::default());
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.version = msl::Version::V2_1;
+    compiler_options.swizzle_buffer_index = 12;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert_eq!(ast.get_compiler_options(), compiler_options);
+}
+
+#[test]
+fn sets_argument_buffer_device_address_space() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.version = spirv_cross::msl::Version::V2_0;
+    compiler_options.enable_argument_buffers = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    ast.set_argument_buffer_device_address_space(0, true);
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("device spvDescriptorSetBuffer0& spvDescriptorSet0"));
+    assert!(!shader.contains("constant spvDescriptorSetBuffer0& spvDescriptorSet0"));
+}
+
+#[test]
+fn sets_argument_buffer_descriptor() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.version = msl::Version::V2_0;
+    compiler_options.enable_argument_buffers = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("constant uniform_buffer_object* "));
+    assert!(!shader.contains("spvDynamicOffsets"));
+
+    ast.set_argument_buffer_descriptor(0, 0, msl::ArgumentBufferDescriptor::InlineUniformBlock);
+    let shader = ast.compile().unwrap();
+    assert!(!shader.contains("constant uniform_buffer_object* "));
+
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&compiler_options).unwrap();
+    ast.set_argument_buffer_descriptor(
+        0,
+        0,
+        msl::ArgumentBufferDescriptor::DynamicBuffer { index: 0 },
+    );
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("spvDynamicOffsets"));
+}
+
+#[test]
+fn ast_sets_enabled_fragment_outputs() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/mrt.asm.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&msl::CompilerOptions::default()).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("[[color(0)]]"));
+    assert!(shader.contains("[[color(1)]]"));
+
+    ast.set_enabled_fragment_outputs(0b1).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("[[color(0)]]"));
+    assert!(!shader.contains("[[color(1)]]"));
+}
+
+#[test]
+fn enables_multiview() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/multiview.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.version = msl::Version::V2_0;
+    compiler_options.multiview = true;
+    compiler_options.view_mask_buffer_index = 20;
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("gl_ViewIndex"));
+    assert!(shader.contains("spvViewMask [[buffer(20)]]"));
+}
+
+#[test]
+fn sets_runtime_array_resource_count() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/texture_array.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.version = msl::Version::V2_0;
+    compiler_options.resource_binding_overrides.insert(
+        msl::ResourceBindingLocation {
+            stage: spirv::ExecutionModel::Fragment,
+            desc_set: 0,
+            binding: 0,
+        },
+        msl::ResourceBinding {
+            texture_id: 2,
+            ..msl::ResourceBinding::array(16)
+        },
+    );
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    assert!(ast
+        .compile()
+        .unwrap()
+        .contains("array<texture2d<float>, 16> u_textures [[texture(2)]]"));
+}
+
+#[test]
+fn gets_stage_output_struct_name() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    ast.set_compiler_options(&msl::CompilerOptions::default())
+        .unwrap();
+
+    assert!(ast.get_stage_output_struct_name().is_err());
+
+    let shader = ast.compile().unwrap();
+    let name = ast.get_stage_output_struct_name().unwrap();
+    assert_eq!(name, "main0_out");
+    assert!(shader.contains(&format!("struct {}\n", name)));
+}
+
+#[test]
+fn gets_stage_output_struct_name_of_selected_entry_point() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/two_fragment_entry_points.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    ast.set_entry_point("shade_blue", spirv::ExecutionModel::Fragment)
+        .unwrap();
+
+    let shader = ast.compile().unwrap();
+    let name = ast.get_stage_output_struct_name().unwrap();
+    assert_eq!(name, "shade_blue_out");
+    assert!(shader.contains(&format!("struct {}\n", name)));
+}
+
+#[test]
+fn gets_sampler_plane_count() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+    let resources = ast.get_shader_resources().unwrap();
+    let sampler_id = resources.separate_samplers[0].id;
+    let image_id = resources.separate_images[0].id;
+
+    let sampler_data = msl::SamplerData {
+        coord: msl::SamplerCoord::Normalized,
+        min_filter: msl::SamplerFilter::Linear,
+        mag_filter: msl::SamplerFilter::Linear,
+        mip_filter: msl::SamplerMipFilter::None,
+        s_address: msl::SamplerAddress::ClampToEdge,
+        t_address: msl::SamplerAddress::ClampToEdge,
+        r_address: msl::SamplerAddress::ClampToEdge,
+        compare_func: msl::SamplerCompareFunc::Always,
+        border_color: msl::SamplerBorderColor::TransparentBlack,
+        lod_clamp_min: msl::LodBase16::ZERO,
+        lod_clamp_max: msl::LodBase16::MAX,
+        max_anisotropy: 0,
+        planes: 3,
+        resolution: msl::FormatResolution::MSL_FORMAT_RESOLUTION_420,
+        chroma_filter: msl::SamplerFilter::Linear,
+        x_chroma_offset: msl::ChromaLocation::CositedEven,
+        y_chroma_offset: msl::ChromaLocation::CositedEven,
+        swizzle: [msl::ComponentSwizzle::Identity; 4],
+        ycbcr_conversion_enable: true,
+        ycbcr_model: msl::SamplerYCbCrModelConversion::MSL_SAMPLER_YCBCR_MODEL_CONVERSION_YCBCR_BT_709,
+        ycbcr_range: msl::SamplerYCbCrRange::MSL_SAMPLER_YCBCR_RANGE_ITU_FULL,
+        bpc: 8,
+    };
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.const_samplers.insert(
+        msl::SamplerLocation {
+            desc_set: 0,
+            binding: 1,
+        },
+        sampler_data,
+    );
+    ast.set_compiler_options(&compiler_options).unwrap();
+
+    assert_eq!(ast.get_sampler_plane_count(sampler_id).unwrap(), 3);
+    assert_eq!(ast.get_sampler_plane_count(image_id).unwrap(), 1);
+}
+
+#[test]
+fn rejects_border_color_without_clamp_to_border() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+
+    let mut sampler_data = msl::SamplerData {
+        coord: msl::SamplerCoord::Normalized,
+        min_filter: msl::SamplerFilter::Linear,
+        mag_filter: msl::SamplerFilter::Linear,
+        mip_filter: msl::SamplerMipFilter::None,
+        s_address: msl::SamplerAddress::ClampToEdge,
+        t_address: msl::SamplerAddress::ClampToEdge,
+        r_address: msl::SamplerAddress::ClampToEdge,
+        compare_func: msl::SamplerCompareFunc::Always,
+        border_color: msl::SamplerBorderColor::OpaqueWhite,
+        lod_clamp_min: msl::LodBase16::ZERO,
+        lod_clamp_max: msl::LodBase16::MAX,
+        max_anisotropy: 0,
+        planes: 0,
+        resolution: msl::FormatResolution::MSL_FORMAT_RESOLUTION_444,
+        chroma_filter: msl::SamplerFilter::Nearest,
+        x_chroma_offset: msl::ChromaLocation::CositedEven,
+        y_chroma_offset: msl::ChromaLocation::CositedEven,
+        swizzle: [msl::ComponentSwizzle::Identity; 4],
+        ycbcr_conversion_enable: false,
+        ycbcr_model: msl::SamplerYCbCrModelConversion::MSL_SAMPLER_YCBCR_MODEL_CONVERSION_RGB_IDENTITY,
+        ycbcr_range: msl::SamplerYCbCrRange::MSL_SAMPLER_YCBCR_RANGE_ITU_FULL,
+        bpc: 8,
+    };
+    let location = msl::SamplerLocation {
+        desc_set: 0,
+        binding: 1,
+    };
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options
+        .const_samplers
+        .insert(location.clone(), sampler_data.clone());
+    assert!(ast.set_compiler_options(&compiler_options).is_err());
+
+    sampler_data.t_address = msl::SamplerAddress::ClampToBorder;
+    compiler_options.const_samplers.insert(location, sampler_data);
+    ast.set_compiler_options(&compiler_options).unwrap();
+    assert!(ast
+        .compile()
+        .unwrap()
+        .contains("border_color::opaque_white"));
+}
+
+#[test]
+fn sets_texture_buffer_native() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/texel_buffer.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<msl::Target>::parse(&module).unwrap();
+
+    let mut compiler_options = msl::CompilerOptions::default();
+    compiler_options.version = msl::Version::V2_1;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("texture2d<float> u_texels [[texture(0)]]"));
+    assert!(!shader.contains("texture_buffer<float>"));
+
+    compiler_options.texture_buffer_native = true;
+    ast.set_compiler_options(&compiler_options).unwrap();
+    let shader = ast.compile().unwrap();
+    assert!(shader.contains("texture_buffer<float> u_texels [[texture(0)]]"));
+}
@@ -0,0 +1,27 @@
+; SPIR-V
+; Version: 1.4
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 12
+; Schema: 0
+               OpCapability RayTracingKHR
+               OpExtension "SPV_KHR_ray_tracing"
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint RayGenerationKHR %main "main" %scenes
+               OpSource GLSL 460
+               OpName %main "main"
+               OpName %scenes "scenes"
+               OpDecorate %scenes DescriptorSet 0
+               OpDecorate %scenes Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+          %6 = OpTypeAccelerationStructureKHR
+       %uint = OpTypeInt 32 0
+     %uint_2 = OpConstant %uint 2
+%_arr_6_uint_2 = OpTypeArray %6 %uint_2
+%_ptr_UniformConstant__arr_6_uint_2 = OpTypePointer UniformConstant %_arr_6_uint_2
+     %scenes = OpVariable %_ptr_UniformConstant__arr_6_uint_2 UniformConstant
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+               OpReturn
+               OpFunctionEnd
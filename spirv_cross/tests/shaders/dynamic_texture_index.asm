@@ -0,0 +1,47 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 34
+; Schema: 0
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %target0 %v_index
+               OpExecutionMode %main OriginUpperLeft
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %target0 "target0"
+               OpName %v_index "v_index"
+               OpName %u_textures "u_textures"
+               OpDecorate %target0 Location 0
+               OpDecorate %v_index Location 0
+               OpDecorate %v_index Flat
+               OpDecorate %u_textures DescriptorSet 0
+               OpDecorate %u_textures Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+    %target0 = OpVariable %_ptr_Output_v4float Output
+        %int = OpTypeInt 32 1
+%_ptr_Input_int = OpTypePointer Input %int
+    %v_index = OpVariable %_ptr_Input_int Input
+      %int_4 = OpConstant %int 4
+         %10 = OpTypeImage %float 2D 0 0 0 1 Unknown
+         %11 = OpTypeSampledImage %10
+%_arr_11_int_4 = OpTypeArray %11 %int_4
+%_ptr_UniformConstant__arr_11_int_4 = OpTypePointer UniformConstant %_arr_11_int_4
+ %u_textures = OpVariable %_ptr_UniformConstant__arr_11_int_4 UniformConstant
+%_ptr_UniformConstant_11 = OpTypePointer UniformConstant %11
+    %v2float = OpTypeVector %float 2
+    %float_0 = OpConstant %float 0
+         %23 = OpConstantComposite %v2float %float_0 %float_0
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %30 = OpLoad %int %v_index
+         %31 = OpAccessChain %_ptr_UniformConstant_11 %u_textures %30
+         %32 = OpLoad %11 %31
+         %33 = OpImageSampleImplicitLod %v4float %32 %23
+               OpStore %target0 %33
+               OpReturn
+               OpFunctionEnd
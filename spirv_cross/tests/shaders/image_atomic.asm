@@ -0,0 +1,34 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 21
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %counters "counters"
+               OpDecorate %counters DescriptorSet 0
+               OpDecorate %counters Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+       %uint = OpTypeInt 32 0
+        %int = OpTypeInt 32 1
+         %10 = OpTypeImage %uint 2D 0 0 0 2 R32ui
+%_ptr_UniformConstant_10 = OpTypePointer UniformConstant %10
+   %counters = OpVariable %_ptr_UniformConstant_10 UniformConstant
+      %v2int = OpTypeVector %int 2
+      %int_0 = OpConstant %int 0
+         %15 = OpConstantComposite %v2int %int_0 %int_0
+     %uint_0 = OpConstant %uint 0
+     %uint_1 = OpConstant %uint 1
+%_ptr_Image_uint = OpTypePointer Image %uint
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %19 = OpImageTexelPointer %_ptr_Image_uint %counters %15 %uint_0
+         %20 = OpAtomicIAdd %uint %19 %uint_1 %uint_0 %uint_1
+               OpReturn
+               OpFunctionEnd
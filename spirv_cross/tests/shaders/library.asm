@@ -0,0 +1,16 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 5
+; Schema: 0
+               OpCapability Addresses
+               OpCapability Linkage
+               OpCapability Kernel
+               OpMemoryModel Physical64 OpenCL
+               OpName %helper "helper"
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+     %helper = OpFunction %void None %3
+          %4 = OpLabel
+               OpReturn
+               OpFunctionEnd
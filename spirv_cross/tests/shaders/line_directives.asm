@@ -0,0 +1,29 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 13
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %target0
+               OpExecutionMode %main OriginUpperLeft
+          %2 = OpString "line_directives.frag"
+               OpSource GLSL 450 %2
+               OpName %main "main"
+               OpName %target0 "target0"
+               OpDecorate %target0 Location 0
+       %void = OpTypeVoid
+          %4 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+    %target0 = OpVariable %_ptr_Output_v4float Output
+    %float_1 = OpConstant %float 1
+         %11 = OpConstantComposite %v4float %float_1 %float_1 %float_1 %float_1
+       %main = OpFunction %void None %4
+          %5 = OpLabel
+               OpLine %2 7 0
+               OpStore %target0 %11
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,40 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 18
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %color
+               OpExecutionMode %main OriginUpperLeft
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %Params "Params"
+               OpMemberName %Params 0 "color"
+               OpName %params "params"
+               OpName %color "color"
+               OpDecorate %Params Block
+               OpMemberDecorate %Params 0 Offset 0
+               OpDecorate %params DescriptorSet 0
+               OpDecorate %params Binding 0
+               OpDecorate %color Location 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+     %Params = OpTypeStruct %v4float
+%_ptr_Uniform_Params = OpTypePointer Uniform %Params
+     %params = OpVariable %_ptr_Uniform_Params Uniform
+%_ptr_Uniform_v4float = OpTypePointer Uniform %v4float
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+      %color = OpVariable %_ptr_Output_v4float Output
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+     %member = OpAccessChain %_ptr_Uniform_v4float %params %int_0
+     %loaded = OpLoad %v4float %member
+               OpStore %color %loaded
+               OpReturn
+               OpFunctionEnd
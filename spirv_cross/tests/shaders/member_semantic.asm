@@ -0,0 +1,34 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 11
+; Schema: 0
+               OpCapability Shader
+               OpExtension "SPV_GOOGLE_decorate_string"
+               OpExtension "SPV_GOOGLE_hlsl_functionality1"
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main"
+               OpSource HLSL 500
+               OpName %main "main"
+               OpName %VertexData "VertexData"
+               OpMemberName %VertexData 0 "position"
+               OpMemberName %VertexData 1 "color"
+               OpName %vertex_data "vertex_data"
+               OpMemberDecorate %VertexData 0 Offset 0
+               OpMemberDecorate %VertexData 1 Offset 16
+               OpMemberDecorateString %VertexData 0 HlslSemanticGOOGLE "POSITION"
+               OpDecorate %VertexData Block
+               OpDecorate %vertex_data DescriptorSet 0
+               OpDecorate %vertex_data Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+ %VertexData = OpTypeStruct %v4float %v4float
+%_ptr_Uniform_VertexData = OpTypePointer Uniform %VertexData
+%vertex_data = OpVariable %_ptr_Uniform_VertexData Uniform
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+               OpReturn
+               OpFunctionEnd
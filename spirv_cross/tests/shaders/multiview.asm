@@ -0,0 +1,31 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 13
+; Schema: 0
+               OpCapability Shader
+               OpCapability MultiView
+               OpExtension "SPV_KHR_multiview"
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %out_view %gl_ViewIndex
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %out_view "out_view"
+               OpName %gl_ViewIndex "gl_ViewIndex"
+               OpDecorate %out_view Location 0
+               OpDecorate %out_view Flat
+               OpDecorate %gl_ViewIndex BuiltIn ViewIndex
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+        %int = OpTypeInt 32 1
+%_ptr_Output_int = OpTypePointer Output %int
+   %out_view = OpVariable %_ptr_Output_int Output
+%_ptr_Input_int = OpTypePointer Input %int
+%gl_ViewIndex = OpVariable %_ptr_Input_int Input
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %12 = OpLoad %int %gl_ViewIndex
+               OpStore %out_view %12
+               OpReturn
+               OpFunctionEnd
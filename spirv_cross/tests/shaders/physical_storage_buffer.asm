@@ -0,0 +1,35 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 12
+; Schema: 0
+               OpCapability Shader
+               OpCapability PhysicalStorageBufferAddresses
+               OpExtension "SPV_KHR_physical_storage_buffer"
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel PhysicalStorageBuffer64 GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %Buffer "Buffer"
+               OpMemberName %Buffer 0 "value"
+               OpName %PushConstants "PushConstants"
+               OpMemberName %PushConstants 0 "buffer"
+               OpName %push_constants "push_constants"
+               OpMemberDecorate %Buffer 0 Offset 0
+               OpDecorate %Buffer Block
+               OpMemberDecorate %PushConstants 0 Offset 0
+               OpDecorate %PushConstants Block
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+       %uint = OpTypeInt 32 0
+     %Buffer = OpTypeStruct %uint
+%_ptr_PhysicalStorageBuffer_Buffer = OpTypePointer PhysicalStorageBuffer %Buffer
+%PushConstants = OpTypeStruct %_ptr_PhysicalStorageBuffer_Buffer
+%_ptr_PushConstant_PushConstants = OpTypePointer PushConstant %PushConstants
+%push_constants = OpVariable %_ptr_PushConstant_PushConstants PushConstant
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,52 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 27
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %_
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %gl_PerVertex "gl_PerVertex"
+               OpMemberName %gl_PerVertex 0 "gl_Position"
+               OpName %_ ""
+               OpName %PushConstants "PushConstants"
+               OpMemberName %PushConstants 0 "position"
+               OpMemberName %PushConstants 1 "scale"
+               OpName %push_constants "push_constants"
+               OpMemberDecorate %gl_PerVertex 0 BuiltIn Position
+               OpDecorate %gl_PerVertex Block
+               OpMemberDecorate %PushConstants 0 NonWritable
+               OpMemberDecorate %PushConstants 0 Offset 0
+               OpMemberDecorate %PushConstants 1 NonWritable
+               OpMemberDecorate %PushConstants 1 Offset 16
+               OpDecorate %PushConstants Block
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%gl_PerVertex = OpTypeStruct %v4float
+%_ptr_Output_gl_PerVertex = OpTypePointer Output %gl_PerVertex
+          %_ = OpVariable %_ptr_Output_gl_PerVertex Output
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+      %int_1 = OpConstant %int 1
+%PushConstants = OpTypeStruct %v4float %float
+%_ptr_PushConstant_PushConstants = OpTypePointer PushConstant %PushConstants
+%push_constants = OpVariable %_ptr_PushConstant_PushConstants PushConstant
+%_ptr_PushConstant_v4float = OpTypePointer PushConstant %v4float
+%_ptr_PushConstant_float = OpTypePointer PushConstant %float
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %21 = OpAccessChain %_ptr_PushConstant_v4float %push_constants %int_0
+         %22 = OpLoad %v4float %21
+         %23 = OpAccessChain %_ptr_PushConstant_float %push_constants %int_1
+         %24 = OpLoad %float %23
+         %25 = OpVectorTimesScalar %v4float %22 %24
+         %26 = OpAccessChain %_ptr_Output_v4float %_ %int_0
+               OpStore %26 %25
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,41 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 20
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %position
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %flip "flip"
+               OpName %position "position"
+               OpDecorate %flip SpecId 0
+               OpDecorate %position BuiltIn Position
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+       %bool = OpTypeBool
+       %flip = OpSpecConstantTrue %bool
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+    %float_0 = OpConstant %float 0
+    %float_1 = OpConstant %float 1
+   %float_n1 = OpConstant %float -1
+     %origin = OpConstantComposite %v4float %float_0 %float_0 %float_0 %float_1
+    %flipped = OpConstantComposite %v4float %float_0 %float_n1 %float_0 %float_1
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+   %position = OpVariable %_ptr_Output_v4float Output
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+               OpSelectionMerge %merge None
+               OpBranchConditional %flip %write_flipped %write_origin
+%write_flipped = OpLabel
+               OpStore %position %flipped
+               OpBranch %merge
+%write_origin = OpLabel
+               OpStore %position %origin
+               OpBranch %merge
+      %merge = OpLabel
+               OpReturn
+               OpFunctionEnd
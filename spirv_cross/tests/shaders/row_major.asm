@@ -0,0 +1,47 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 21
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %position
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %Transforms "Transforms"
+               OpMemberName %Transforms 0 "model"
+               OpMemberName %Transforms 1 "view"
+               OpName %transforms "transforms"
+               OpName %position "position"
+               OpMemberDecorate %Transforms 0 ColMajor
+               OpMemberDecorate %Transforms 0 Offset 0
+               OpMemberDecorate %Transforms 0 MatrixStride 16
+               OpMemberDecorate %Transforms 1 RowMajor
+               OpMemberDecorate %Transforms 1 Offset 64
+               OpMemberDecorate %Transforms 1 MatrixStride 16
+               OpDecorate %Transforms Block
+               OpDecorate %transforms DescriptorSet 0
+               OpDecorate %transforms Binding 0
+               OpDecorate %position BuiltIn Position
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%mat4v4float = OpTypeMatrix %v4float 4
+ %Transforms = OpTypeStruct %mat4v4float %mat4v4float
+%_ptr_Uniform_Transforms = OpTypePointer Uniform %Transforms
+ %transforms = OpVariable %_ptr_Uniform_Transforms Uniform
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+   %position = OpVariable %_ptr_Output_v4float Output
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+%_ptr_Uniform_mat4v4float = OpTypePointer Uniform %mat4v4float
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %18 = OpAccessChain %_ptr_Uniform_mat4v4float %transforms %int_0
+         %19 = OpLoad %mat4v4float %18
+         %20 = OpCompositeExtract %v4float %19 0
+               OpStore %position %20
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,37 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 17
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %Data "Data"
+               OpMemberName %Data 0 "values"
+               OpName %data "data"
+               OpDecorate %_runtimearr_uint ArrayStride 4
+               OpMemberDecorate %Data 0 Offset 0
+               OpDecorate %Data BufferBlock
+               OpDecorate %data DescriptorSet 0
+               OpDecorate %data Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+       %uint = OpTypeInt 32 0
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+%_runtimearr_uint = OpTypeRuntimeArray %uint
+       %Data = OpTypeStruct %_runtimearr_uint
+%_ptr_Uniform_Data = OpTypePointer Uniform %Data
+       %data = OpVariable %_ptr_Uniform_Data Uniform
+%_ptr_Uniform_uint = OpTypePointer Uniform %uint
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %15 = OpArrayLength %uint %data 0
+         %16 = OpAccessChain %_ptr_Uniform_uint %data %int_0 %int_0
+               OpStore %16 %15
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,62 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 36
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %uv %frag_color
+               OpExecutionMode %main OriginUpperLeft
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %shadow_map "shadow_map"
+               OpName %albedo "albedo"
+               OpName %linear_sampler "linear_sampler"
+               OpName %uv "uv"
+               OpName %frag_color "frag_color"
+               OpDecorate %shadow_map DescriptorSet 0
+               OpDecorate %shadow_map Binding 0
+               OpDecorate %albedo DescriptorSet 0
+               OpDecorate %albedo Binding 1
+               OpDecorate %linear_sampler DescriptorSet 0
+               OpDecorate %linear_sampler Binding 2
+               OpDecorate %uv Location 0
+               OpDecorate %frag_color Location 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v2float = OpTypeVector %float 2
+    %v3float = OpTypeVector %float 3
+    %v4float = OpTypeVector %float 4
+%depth_image = OpTypeImage %float 2D 1 0 0 1 Unknown
+%color_image = OpTypeImage %float 2D 0 0 0 1 Unknown
+    %sampler = OpTypeSampler
+%depth_sampled_image = OpTypeSampledImage %depth_image
+%color_sampled_image = OpTypeSampledImage %color_image
+%_ptr_UniformConstant_depth_image = OpTypePointer UniformConstant %depth_image
+%_ptr_UniformConstant_color_image = OpTypePointer UniformConstant %color_image
+%_ptr_UniformConstant_sampler = OpTypePointer UniformConstant %sampler
+ %shadow_map = OpVariable %_ptr_UniformConstant_depth_image UniformConstant
+     %albedo = OpVariable %_ptr_UniformConstant_color_image UniformConstant
+%linear_sampler = OpVariable %_ptr_UniformConstant_sampler UniformConstant
+%_ptr_Input_v3float = OpTypePointer Input %v3float
+         %uv = OpVariable %_ptr_Input_v3float Input
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+ %frag_color = OpVariable %_ptr_Output_v4float Output
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+     %uv_val = OpLoad %v3float %uv
+      %coord = OpVectorShuffle %v2float %uv_val %uv_val 0 1
+       %dref = OpCompositeExtract %float %uv_val 2
+ %shadow_val = OpLoad %depth_image %shadow_map
+%sampler_val = OpLoad %sampler %linear_sampler
+%shadow_sampled = OpSampledImage %depth_sampled_image %shadow_val %sampler_val
+ %visibility = OpImageSampleDrefImplicitLod %float %shadow_sampled %coord %dref
+ %albedo_val = OpLoad %color_image %albedo
+%albedo_sampled = OpSampledImage %color_sampled_image %albedo_val %sampler_val
+      %color = OpImageSampleImplicitLod %v4float %albedo_sampled %coord
+     %result = OpVectorTimesScalar %v4float %color %visibility
+               OpStore %frag_color %result
+               OpReturn
+               OpFunctionEnd
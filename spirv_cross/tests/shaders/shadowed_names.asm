@@ -0,0 +1,45 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 21
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %color_scale %color
+               OpExecutionMode %main OriginUpperLeft
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %color_scale "color_scale"
+               OpName %color "color"
+               OpName %passthrough "passthrough"
+               OpName %result_param "result"
+               OpName %result "result"
+               OpDecorate %color_scale Location 1
+               OpDecorate %color Location 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%float_to_float = OpTypeFunction %float %float
+    %float_1 = OpConstant %float 1
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+%color_scale = OpVariable %_ptr_Output_v4float Output
+      %color = OpVariable %_ptr_Output_v4float Output
+%_ptr_Function_float = OpTypePointer Function %float
+%passthrough = OpFunction %float None %float_to_float
+%result_param = OpFunctionParameter %float
+          %6 = OpLabel
+               OpReturnValue %result_param
+               OpFunctionEnd
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+     %result = OpVariable %_ptr_Function_float Function
+     %called = OpFunctionCall %float %passthrough %float_1
+               OpStore %result %called
+     %loaded = OpLoad %float %result
+     %vector = OpCompositeConstruct %v4float %loaded %loaded %loaded %loaded
+               OpStore %color %vector
+               OpStore %color_scale %vector
+               OpReturn
+               OpFunctionEnd
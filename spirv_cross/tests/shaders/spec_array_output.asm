@@ -0,0 +1,26 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 11
+; Schema: 0
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %v_weights
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %WEIGHT_COUNT "WEIGHT_COUNT"
+               OpName %v_weights "v_weights"
+               OpDecorate %WEIGHT_COUNT SpecId 0
+               OpDecorate %v_weights Location 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+       %uint = OpTypeInt 32 0
+%WEIGHT_COUNT = OpSpecConstant %uint 2
+%_arr_float_WEIGHT_COUNT = OpTypeArray %float %WEIGHT_COUNT
+%_ptr_Output__arr_float_WEIGHT_COUNT = OpTypePointer Output %_arr_float_WEIGHT_COUNT
+  %v_weights = OpVariable %_ptr_Output__arr_float_WEIGHT_COUNT Output
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+               OpReturn
+               OpFunctionEnd
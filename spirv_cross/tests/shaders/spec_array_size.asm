@@ -0,0 +1,32 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 16
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %shared_values "shared_values"
+               OpDecorate %local_size_x SpecId 0
+               OpDecorate %value_count SpecId 3
+               OpDecorate %10 BuiltIn WorkgroupSize
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+       %uint = OpTypeInt 32 0
+     %v3uint = OpTypeVector %uint 3
+%local_size_x = OpSpecConstant %uint 1
+     %uint_1 = OpConstant %uint 1
+         %10 = OpSpecConstantComposite %v3uint %local_size_x %uint_1 %uint_1
+      %float = OpTypeFloat 32
+%value_count = OpSpecConstant %uint 4
+         %13 = OpTypeArray %float %value_count
+%_ptr_Workgroup_13 = OpTypePointer Workgroup %13
+%shared_values = OpVariable %_ptr_Workgroup_13 Workgroup
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+               OpReturn
+               OpFunctionEnd
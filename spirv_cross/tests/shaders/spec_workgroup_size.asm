@@ -0,0 +1,28 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 12
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpDecorate %local_size_x SpecId 0
+               OpDecorate %local_size_y SpecId 1
+               OpDecorate %local_size_z SpecId 2
+               OpDecorate %11 BuiltIn WorkgroupSize
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+       %uint = OpTypeInt 32 0
+     %v3uint = OpTypeVector %uint 3
+%local_size_x = OpSpecConstant %uint 1
+%local_size_y = OpSpecConstant %uint 1
+%local_size_z = OpSpecConstant %uint 1
+         %11 = OpSpecConstantComposite %v3uint %local_size_x %local_size_y %local_size_z
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+               OpReturn
+               OpFunctionEnd
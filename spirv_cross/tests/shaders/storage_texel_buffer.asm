@@ -0,0 +1,39 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 23
+; Schema: 0
+               OpCapability Shader
+               OpCapability SampledBuffer
+               OpCapability ImageBuffer
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main"
+               OpExecutionMode %main LocalSize 1 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %u_texels "u_texels"
+               OpName %u_output "u_output"
+               OpDecorate %u_texels DescriptorSet 0
+               OpDecorate %u_texels Binding 0
+               OpDecorate %u_output DescriptorSet 0
+               OpDecorate %u_output Binding 1
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+         %10 = OpTypeImage %float Buffer 0 0 0 1 Unknown
+%_ptr_UniformConstant_10 = OpTypePointer UniformConstant %10
+   %u_texels = OpVariable %_ptr_UniformConstant_10 UniformConstant
+         %13 = OpTypeImage %float Buffer 0 0 0 2 Rgba32f
+%_ptr_UniformConstant_13 = OpTypePointer UniformConstant %13
+   %u_output = OpVariable %_ptr_UniformConstant_13 UniformConstant
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %20 = OpLoad %10 %u_texels
+         %21 = OpImageFetch %v4float %20 %int_0
+         %22 = OpLoad %13 %u_output
+               OpImageWrite %22 %int_0 %21
+               OpReturn
+               OpFunctionEnd
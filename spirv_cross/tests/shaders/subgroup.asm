@@ -0,0 +1,40 @@
+; SPIR-V
+; Version: 1.3
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 17
+; Schema: 0
+               OpCapability Shader
+               OpCapability GroupNonUniform
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint GLCompute %main "main" %gl_SubgroupSize
+               OpExecutionMode %main LocalSize 64 1 1
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %Output "Output"
+               OpMemberName %Output 0 "subgroup_size"
+               OpName %output "output"
+               OpName %gl_SubgroupSize "gl_SubgroupSize"
+               OpMemberDecorate %Output 0 Offset 0
+               OpDecorate %Output BufferBlock
+               OpDecorate %output DescriptorSet 0
+               OpDecorate %output Binding 0
+               OpDecorate %gl_SubgroupSize BuiltIn SubgroupSize
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+       %uint = OpTypeInt 32 0
+     %Output = OpTypeStruct %uint
+%_ptr_Uniform_Output = OpTypePointer Uniform %Output
+     %output = OpVariable %_ptr_Uniform_Output Uniform
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+%_ptr_Input_uint = OpTypePointer Input %uint
+%gl_SubgroupSize = OpVariable %_ptr_Input_uint Input
+%_ptr_Uniform_uint = OpTypePointer Uniform %uint
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %14 = OpLoad %uint %gl_SubgroupSize
+         %15 = OpAccessChain %_ptr_Uniform_uint %output %int_0
+               OpStore %15 %14
+               OpReturn
+               OpFunctionEnd
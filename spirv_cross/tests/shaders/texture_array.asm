@@ -0,0 +1,52 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 35
+; Schema: 0
+               OpCapability Shader
+               OpCapability RuntimeDescriptorArray
+               OpExtension "SPV_EXT_descriptor_indexing"
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %main "main" %target0
+               OpExecutionMode %main OriginUpperLeft
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %target0 "target0"
+               OpName %u_textures "u_textures"
+               OpName %u_sampler "u_sampler"
+               OpDecorate %target0 Location 0
+               OpDecorate %u_textures DescriptorSet 0
+               OpDecorate %u_textures Binding 0
+               OpDecorate %u_sampler DescriptorSet 0
+               OpDecorate %u_sampler Binding 1
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+    %target0 = OpVariable %_ptr_Output_v4float Output
+         %10 = OpTypeImage %float 2D 0 0 0 1 Unknown
+%_runtimearr_10 = OpTypeRuntimeArray %10
+%_ptr_UniformConstant__runtimearr_10 = OpTypePointer UniformConstant %_runtimearr_10
+ %u_textures = OpVariable %_ptr_UniformConstant__runtimearr_10 UniformConstant
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+%_ptr_UniformConstant_10 = OpTypePointer UniformConstant %10
+         %17 = OpTypeSampler
+%_ptr_UniformConstant_17 = OpTypePointer UniformConstant %17
+  %u_sampler = OpVariable %_ptr_UniformConstant_17 UniformConstant
+         %20 = OpTypeSampledImage %10
+    %v2float = OpTypeVector %float 2
+    %float_0 = OpConstant %float 0
+         %23 = OpConstantComposite %v2float %float_0 %float_0
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %30 = OpAccessChain %_ptr_UniformConstant_10 %u_textures %int_0
+         %31 = OpLoad %10 %30
+         %32 = OpLoad %17 %u_sampler
+         %33 = OpSampledImage %20 %31 %32
+         %34 = OpImageSampleImplicitLod %v4float %33 %23
+               OpStore %target0 %34
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,37 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 16
+; Schema: 0
+               OpCapability Shader
+          %1 = OpExtInstImport "GLSL.std.450"
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Fragment %shade_red "shade_red" %color
+               OpEntryPoint Fragment %shade_blue "shade_blue" %color
+               OpExecutionMode %shade_red OriginUpperLeft
+               OpExecutionMode %shade_blue OriginUpperLeft
+               OpSource GLSL 450
+               OpName %shade_red "shade_red"
+               OpName %shade_blue "shade_blue"
+               OpName %color "color"
+               OpDecorate %color Location 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+    %float_0 = OpConstant %float 0
+    %float_1 = OpConstant %float 1
+        %red = OpConstantComposite %v4float %float_1 %float_0 %float_0 %float_1
+       %blue = OpConstantComposite %v4float %float_0 %float_0 %float_1 %float_1
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+      %color = OpVariable %_ptr_Output_v4float Output
+  %shade_red = OpFunction %void None %3
+          %5 = OpLabel
+               OpStore %color %red
+               OpReturn
+               OpFunctionEnd
+ %shade_blue = OpFunction %void None %3
+          %6 = OpLabel
+               OpStore %color %blue
+               OpReturn
+               OpFunctionEnd
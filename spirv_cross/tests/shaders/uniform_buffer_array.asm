@@ -0,0 +1,41 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 22
+; Schema: 0
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %position
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %Params "Params"
+               OpMemberName %Params 0 "offset"
+               OpName %params "params"
+               OpName %position "position"
+               OpDecorate %position BuiltIn Position
+               OpMemberDecorate %Params 0 Offset 0
+               OpDecorate %Params Block
+               OpDecorate %params DescriptorSet 0
+               OpDecorate %params Binding 0
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v4float = OpTypeVector %float 4
+%_ptr_Output_v4float = OpTypePointer Output %v4float
+   %position = OpVariable %_ptr_Output_v4float Output
+        %int = OpTypeInt 32 1
+      %int_0 = OpConstant %int 0
+       %uint = OpTypeInt 32 0
+     %uint_2 = OpConstant %uint 2
+     %Params = OpTypeStruct %v4float
+%_arr_Params_uint_2 = OpTypeArray %Params %uint_2
+%_ptr_Uniform__arr_Params_uint_2 = OpTypePointer Uniform %_arr_Params_uint_2
+     %params = OpVariable %_ptr_Uniform__arr_Params_uint_2 Uniform
+%_ptr_Uniform_v4float = OpTypePointer Uniform %v4float
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+         %20 = OpAccessChain %_ptr_Uniform_v4float %params %int_0 %int_0
+         %21 = OpLoad %v4float %20
+               OpStore %position %21
+               OpReturn
+               OpFunctionEnd
@@ -0,0 +1,24 @@
+; SPIR-V
+; Version: 1.0
+; Generator: Khronos SPIR-V Tools Assembler; 0
+; Bound: 12
+; Schema: 0
+               OpCapability Shader
+               OpMemoryModel Logical GLSL450
+               OpEntryPoint Vertex %main "main" %v_uv
+               OpSource GLSL 450
+               OpName %main "main"
+               OpName %v_uv "v_uv"
+       %void = OpTypeVoid
+          %3 = OpTypeFunction %void
+      %float = OpTypeFloat 32
+    %v2float = OpTypeVector %float 2
+%_ptr_Output_v2float = OpTypePointer Output %v2float
+       %v_uv = OpVariable %_ptr_Output_v2float Output
+    %float_0 = OpConstant %float 0
+         %11 = OpConstantComposite %v2float %float_0 %float_0
+       %main = OpFunction %void None %3
+          %5 = OpLabel
+               OpStore %v_uv %11
+               OpReturn
+               OpFunctionEnd
@@ -3,6 +3,66 @@ use spirv_cross::{hlsl as lang, spirv};
 mod common;
 use crate::common::words_from_bytes;
 
+#[test]
+fn spirv_names_round_trip() {
+    use spirv::{BuiltIn, Decoration, ExecutionModel};
+
+    let execution_models = [
+        (ExecutionModel::Vertex, "Vertex"),
+        (ExecutionModel::TessellationControl, "TessellationControl"),
+        (ExecutionModel::TessellationEvaluation, "TessellationEvaluation"),
+        (ExecutionModel::Geometry, "Geometry"),
+        (ExecutionModel::Fragment, "Fragment"),
+        (ExecutionModel::GlCompute, "GLCompute"),
+        (ExecutionModel::Kernel, "Kernel"),
+        (ExecutionModel::TaskNv, "TaskNV"),
+        (ExecutionModel::MeshNv, "MeshNV"),
+        (ExecutionModel::RayGenerationKhr, "RayGenerationKHR"),
+        (ExecutionModel::IntersectionKhr, "IntersectionKHR"),
+        (ExecutionModel::AnyHitKhr, "AnyHitKHR"),
+        (ExecutionModel::ClosestHitKhr, "ClosestHitKHR"),
+        (ExecutionModel::MissKhr, "MissKHR"),
+        (ExecutionModel::CallableKhr, "CallableKHR"),
+        (ExecutionModel::TaskExt, "TaskEXT"),
+        (ExecutionModel::MeshExt, "MeshEXT"),
+    ];
+    for &(execution_model, name) in execution_models.iter() {
+        assert_eq!(execution_model.to_string(), name);
+        assert_eq!(name.parse::<ExecutionModel>().unwrap(), execution_model);
+    }
+    assert_eq!(
+        "vertex".parse::<ExecutionModel>().unwrap(),
+        ExecutionModel::Vertex
+    );
+    assert!("Vert".parse::<ExecutionModel>().is_err());
+
+    let decorations = [
+        (Decoration::Binding, "Binding"),
+        (Decoration::DescriptorSet, "DescriptorSet"),
+        (Decoration::GlslShared, "GLSLShared"),
+        (Decoration::FpRoundingMode, "FPRoundingMode"),
+        (Decoration::HlslSemanticGoogle, "HlslSemanticGOOGLE"),
+        (Decoration::MediaBlockIointel, "MediaBlockIOINTEL"),
+    ];
+    for &(decoration, name) in decorations.iter() {
+        assert_eq!(decoration.to_string(), name);
+        assert_eq!(name.parse::<Decoration>().unwrap(), decoration);
+    }
+    assert!("Bindings".parse::<Decoration>().is_err());
+
+    let built_ins = [
+        (BuiltIn::Position, "Position"),
+        (BuiltIn::LaunchIdKhr, "LaunchIdKHR"),
+        (BuiltIn::BaryCoordSmoothAmd, "BaryCoordSmoothAMD"),
+        (BuiltIn::WarpsPerSmnv, "WarpsPerSMNV"),
+    ];
+    for &(built_in, name) in built_ins.iter() {
+        assert_eq!(built_in.to_string(), name);
+        assert_eq!(name.parse::<BuiltIn>().unwrap(), built_in);
+    }
+    assert!("".parse::<BuiltIn>().is_err());
+}
+
 #[test]
 fn ast_gets_multiple_entry_points() {
     let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
@@ -47,48 +107,946 @@ fn ast_gets_shader_resources() {
     assert_eq!(stage_outputs.len(), 1);
     assert!(stage_outputs
         .iter()
-        .any(|stage_output| stage_output.name == "v_normal"));
-    assert_eq!(shader_resources.subpass_inputs.len(), 0);
-    assert_eq!(shader_resources.storage_images.len(), 0);
-    assert_eq!(shader_resources.sampled_images.len(), 0);
-    assert_eq!(shader_resources.atomic_counters.len(), 0);
-    assert_eq!(shader_resources.push_constant_buffers.len(), 0);
-    assert_eq!(shader_resources.separate_images.len(), 0);
-    assert_eq!(shader_resources.separate_samplers.len(), 0);
+        .any(|stage_output| stage_output.name == "v_normal"));
+    assert_eq!(shader_resources.subpass_inputs.len(), 0);
+    assert_eq!(shader_resources.storage_images.len(), 0);
+    assert_eq!(shader_resources.sampled_images.len(), 0);
+    assert_eq!(shader_resources.atomic_counters.len(), 0);
+    assert_eq!(shader_resources.push_constant_buffers.len(), 0);
+    assert_eq!(shader_resources.separate_images.len(), 0);
+    assert_eq!(shader_resources.separate_samplers.len(), 0);
+}
+
+#[test]
+fn ast_reflects_module() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/workgroup.comp.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let reflection = ast.reflect().unwrap();
+    assert_eq!(reflection.entry_points, ast.get_entry_points().unwrap());
+    assert_eq!(
+        reflection.specialization_constants,
+        ast.get_specialization_constants().unwrap()
+    );
+    assert_eq!(
+        reflection.work_group_size_specialization_constants,
+        ast.get_work_group_size_specialization_constants().unwrap()
+    );
+    assert_eq!(reflection.shader_resources.uniform_buffers.len(), 0);
+}
+
+#[test]
+fn ast_gets_shader_resources_for_active_variables() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/vs_and_fs.asm.spv"
+    )));
+
+    let mut cases = vec![
+        (String::from("main_vs"), spirv::ExecutionModel::Vertex, 0),
+        (String::from("main_fs"), spirv::ExecutionModel::Fragment, 1),
+    ];
+
+    for (name, execution_model, expected_stage_outputs) in cases.drain(..) {
+        let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+        let mut compiler_options = lang::CompilerOptions::default();
+        compiler_options.entry_point = Some((name, execution_model));
+        ast.set_compiler_options(&compiler_options).unwrap();
+
+        assert_eq!(ast.get_shader_resources().unwrap().stage_outputs.len(), 1);
+
+        let active_variables = ast.get_active_interface_variables().unwrap();
+        let shader_resources = ast
+            .get_shader_resources_for_active_variables(&active_variables)
+            .unwrap();
+        assert_eq!(
+            shader_resources.stage_outputs.len(),
+            expected_stage_outputs
+        );
+    }
+}
+
+#[test]
+fn ast_sets_entry_point() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/vs_and_fs.asm.spv"
+    )));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(ast.primary_stage(), Ok(spirv::ShaderStage::Vertex));
+
+    ast.set_entry_point("main_fs", spirv::ExecutionModel::Fragment)
+        .unwrap();
+    assert_eq!(ast.primary_stage(), Ok(spirv::ShaderStage::Fragment));
+    let active_variables = ast.get_active_interface_variables().unwrap();
+    let shader_resources = ast
+        .get_shader_resources_for_active_variables(&active_variables)
+        .unwrap();
+    assert_eq!(shader_resources.stage_outputs.len(), 1);
+
+    assert!(ast
+        .set_entry_point("main_fs", spirv::ExecutionModel::Vertex)
+        .is_err());
+}
+
+#[test]
+fn ast_gets_active_io_variables() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let shader_resources = ast.get_shader_resources().unwrap();
+    let active_io = ast.get_active_io_variables().unwrap();
+    for stage_input in &shader_resources.stage_inputs {
+        assert!(active_io.inputs.contains(&stage_input.id));
+    }
+    assert_eq!(active_io.inputs.len(), 2);
+    assert!(active_io
+        .outputs
+        .contains(&shader_resources.stage_outputs[0].id));
+    assert!(active_io
+        .resources
+        .contains(&shader_resources.uniform_buffers[0].id));
+    assert_eq!(active_io.resources.len(), 1);
+}
+
+#[test]
+fn ast_gets_decoration() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let stage_inputs = ast.get_shader_resources().unwrap().stage_inputs;
+    let decoration = ast
+        .get_decoration(stage_inputs[0].id, spirv::Decoration::DescriptorSet)
+        .unwrap();
+    assert_eq!(decoration, 0);
+}
+
+#[test]
+fn ast_gets_binary_offsets() {
+    let words = words_from_bytes(include_bytes!("shaders/simple.vert.spv"));
+    let module = spirv::Module::from_words(words);
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let uniform_buffer = ast.get_shader_resources().unwrap().uniform_buffers[0].id;
+    let offsets = ast.get_binary_offsets(spirv::Decoration::DescriptorSet).unwrap();
+    assert_eq!(offsets.len(), 1);
+    assert_eq!(offsets[0].0, uniform_buffer);
+    assert_eq!(
+        ast.get_binary_offset_for_decoration(uniform_buffer, spirv::Decoration::DescriptorSet)
+            .unwrap(),
+        Some(offsets[0].1)
+    );
+
+    let mut patched_words = words.to_vec();
+    patched_words[offsets[0].1 as usize] = 7;
+    let module = spirv::Module::from_words(&patched_words);
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_decoration(uniform_buffer, spirv::Decoration::DescriptorSet)
+            .unwrap(),
+        7
+    );
+}
+
+#[test]
+fn ast_sets_decoration() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let stage_inputs = ast.get_shader_resources().unwrap().stage_inputs;
+    let updated_value = 3;
+    ast.set_decoration(
+        stage_inputs[0].id,
+        spirv::Decoration::DescriptorSet,
+        updated_value,
+    )
+    .unwrap();
+    assert_eq!(
+        ast.get_decoration(stage_inputs[0].id, spirv::Decoration::DescriptorSet)
+            .unwrap(),
+        updated_value
+    );
+}
+
+#[test]
+fn ast_applies_decorations() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let uniform_buffer = ast.get_shader_resources().unwrap().uniform_buffers[0].id;
+    ast.apply_decorations(&[
+        (uniform_buffer, spirv::Decoration::DescriptorSet, 2),
+        (uniform_buffer, spirv::Decoration::Binding, 4),
+    ])
+    .unwrap();
+    assert_eq!(
+        ast.get_decoration(uniform_buffer, spirv::Decoration::DescriptorSet)
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        ast.get_decoration(uniform_buffer, spirv::Decoration::Binding)
+            .unwrap(),
+        4
+    );
+
+    let id_bound = ast.get_id_bound().unwrap();
+    assert_eq!(
+        ast.apply_decorations(&[
+            (uniform_buffer, spirv::Decoration::Binding, 5),
+            (id_bound, spirv::Decoration::Binding, 6),
+            (uniform_buffer, spirv::Decoration::Binding, 7),
+        ]),
+        Err((
+            1,
+            spirv_cross::ErrorCode::InvalidArgument(format!("id {} is out of bounds", id_bound))
+        ))
+    );
+    assert_eq!(
+        ast.get_decoration(uniform_buffer, spirv::Decoration::Binding)
+            .unwrap(),
+        5
+    );
+}
+
+#[test]
+fn ast_sets_typed_decorations() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let shader_resources = ast.get_shader_resources().unwrap();
+    let uniform_buffer = shader_resources.uniform_buffers[0].id;
+    ast.set_binding(uniform_buffer, 4).unwrap();
+    ast.set_descriptor_set(uniform_buffer, 2).unwrap();
+    assert_eq!(
+        ast.get_decoration(uniform_buffer, spirv::Decoration::Binding)
+            .unwrap(),
+        4
+    );
+    assert_eq!(
+        ast.get_decoration(uniform_buffer, spirv::Decoration::DescriptorSet)
+            .unwrap(),
+        2
+    );
+
+    let stage_output = shader_resources.stage_outputs[0].id;
+    ast.set_location(stage_output, 3).unwrap();
+    assert_eq!(
+        ast.get_decoration(stage_output, spirv::Decoration::Location)
+            .unwrap(),
+        3
+    );
+
+    ast.set_builtin(stage_output, spirv::BuiltIn::PointSize)
+        .unwrap();
+    // `PointSize` is 1 in the SPIR-V specification.
+    assert_eq!(
+        ast.get_decoration(stage_output, spirv::Decoration::BuiltIn)
+            .unwrap(),
+        1
+    );
+}
+
+#[test]
+fn ast_sets_decoration_string() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let stage_inputs = ast.get_shader_resources().unwrap().stage_inputs;
+    assert_eq!(
+        ast.get_decoration_string(stage_inputs[0].id, spirv::Decoration::HlslSemanticGoogle)
+            .unwrap(),
+        ""
+    );
+    ast.set_decoration_string(
+        stage_inputs[0].id,
+        spirv::Decoration::HlslSemanticGoogle,
+        "POSITION",
+    )
+    .unwrap();
+    assert_eq!(
+        ast.get_decoration_string(stage_inputs[0].id, spirv::Decoration::HlslSemanticGoogle)
+            .unwrap(),
+        "POSITION"
+    );
+}
+
+#[test]
+fn ast_gets_member_decoration_string() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/member_semantic.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let uniform_buffers = ast.get_shader_resources().unwrap().uniform_buffers;
+    let id = uniform_buffers[0].base_type_id;
+    assert_eq!(
+        ast.get_member_decoration_string(id, 0, spirv::Decoration::HlslSemanticGoogle)
+            .unwrap(),
+        "POSITION"
+    );
+    assert_eq!(
+        ast.get_member_decoration_string(id, 1, spirv::Decoration::HlslSemanticGoogle)
+            .unwrap(),
+        ""
+    );
+}
+
+#[test]
+fn image_format_gets_components_and_type() {
+    use spirv::{FormatNumericType, ImageFormat};
+
+    assert_eq!(ImageFormat::Unknown.components_and_type(), None);
+    assert_eq!(
+        ImageFormat::Rgba8.components_and_type(),
+        Some((4, FormatNumericType::Unorm))
+    );
+    assert_eq!(
+        ImageFormat::R11fG11fB10f.components_and_type(),
+        Some((3, FormatNumericType::Float))
+    );
+    assert_eq!(
+        ImageFormat::Rg16Snorm.components_and_type(),
+        Some((2, FormatNumericType::Snorm))
+    );
+    assert_eq!(
+        ImageFormat::R32ui.components_and_type(),
+        Some((1, FormatNumericType::Uint))
+    );
+}
+
+#[test]
+fn type_gets_size_for_layout_rule() {
+    use spirv::{LayoutRule, Type};
+
+    let float = |vecsize, columns, array: Vec<u32>| Type::Float {
+        width: 32,
+        vecsize,
+        columns,
+        array_size_literal: vec![true; array.len()],
+        array,
+    };
+
+    assert_eq!(float(3, 1, vec![]).size(LayoutRule::Std140), Some(12));
+    assert_eq!(float(3, 1, vec![]).size(LayoutRule::Std430), Some(12));
+
+    // Matrix columns are padded to a `vec4` under std140.
+    assert_eq!(float(2, 2, vec![]).size(LayoutRule::Std140), Some(32));
+    assert_eq!(float(2, 2, vec![]).size(LayoutRule::Std430), Some(16));
+    assert_eq!(float(3, 3, vec![]).size(LayoutRule::Std140), Some(48));
+    assert_eq!(float(4, 4, vec![]).size(LayoutRule::Std430), Some(64));
+
+    assert_eq!(float(1, 1, vec![4]).size(LayoutRule::Std140), Some(64));
+    assert_eq!(float(1, 1, vec![4]).size(LayoutRule::Std430), Some(16));
+    assert_eq!(float(3, 1, vec![2, 3]).size(LayoutRule::Std430), Some(96));
+    assert_eq!(float(1, 1, vec![0]).size(LayoutRule::Std430), None);
+
+    let half = Type::Half {
+        width: 16,
+        vecsize: 3,
+        columns: 1,
+        array: vec![],
+        array_size_literal: vec![],
+    };
+    assert_eq!(half.size(LayoutRule::Std430), Some(6));
+
+    assert_eq!(
+        Type::Sampler {
+            array: vec![],
+            array_size_literal: vec![],
+        }
+        .size(LayoutRule::Std430),
+        None
+    );
+}
+
+#[test]
+fn ast_rejects_non_atomic_counter_info() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let uniform_buffers = ast.get_shader_resources().unwrap().uniform_buffers;
+    assert!(ast.get_atomic_counter_info(uniform_buffers[0].id).is_err());
+}
+
+#[test]
+fn ast_gets_push_constant_decorations() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/push_constant.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let shader_resources = ast.get_shader_resources().unwrap();
+    let decorations = ast
+        .get_push_constant_decorations(shader_resources.push_constant_buffers[0].id)
+        .unwrap();
+    assert!(decorations.contains(&spirv::Decoration::NonWritable));
+    assert!(ast
+        .get_push_constant_decorations(shader_resources.push_constant_buffers[0].base_type_id)
+        .is_err());
+}
+
+#[test]
+fn ast_gets_push_constant_range() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/push_constant.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_push_constant_range(),
+        Ok(Some(spirv::PushConstantRange {
+            offset: 0,
+            size: 20,
+            stages: vec![spirv::ShaderStage::Vertex],
+        }))
+    );
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(ast.get_push_constant_range(), Ok(None));
+}
+
+#[test]
+fn ast_gets_binding() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/subpass_input.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let shader_resources = ast.get_shader_resources().unwrap();
+    assert_eq!(
+        ast.get_binding(shader_resources.subpass_inputs[0].id),
+        Ok(spirv::Binding {
+            set: Some(0),
+            binding: Some(2),
+            location: None,
+            input_attachment_index: Some(1),
+        })
+    );
+    assert_eq!(
+        ast.get_binding(shader_resources.stage_outputs[0].id),
+        Ok(spirv::Binding {
+            location: Some(0),
+            ..spirv::Binding::default()
+        })
+    );
+}
+
+#[test]
+fn ast_gets_input_attachment_index() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/subpass_input.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let shader_resources = ast.get_shader_resources().unwrap();
+    assert_eq!(
+        ast.get_input_attachment_index(shader_resources.subpass_inputs[0].id),
+        Ok(1)
+    );
+    assert!(ast
+        .get_input_attachment_index(shader_resources.stage_outputs[0].id)
+        .is_err());
+}
+
+#[test]
+fn ast_gets_primary_stage() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(ast.primary_stage().unwrap(), spirv::ShaderStage::Vertex);
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/raygen.asm.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.primary_stage().unwrap(),
+        spirv::ShaderStage::RayGeneration
+    );
+}
+
+#[test]
+fn shader_stage_converts_from_execution_model() {
+    use std::convert::TryFrom;
+
+    assert_eq!(
+        spirv::ShaderStage::try_from(spirv::ExecutionModel::GlCompute).unwrap(),
+        spirv::ShaderStage::Compute
+    );
+    assert_eq!(
+        spirv::ShaderStage::try_from(spirv::ExecutionModel::MeshNv).unwrap(),
+        spirv::ShaderStage::Mesh
+    );
+    assert!(spirv::ShaderStage::try_from(spirv::ExecutionModel::Kernel).is_err());
+    assert_eq!(
+        spirv::ExecutionModel::from(spirv::ShaderStage::Fragment),
+        spirv::ExecutionModel::Fragment
+    );
+}
+
+#[test]
+fn ast_uses_builtin() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/array.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert!(ast.uses_builtin(spirv::BuiltIn::VertexIndex).unwrap());
+    assert!(ast.uses_builtin(spirv::BuiltIn::Position).unwrap());
+    assert!(!ast.uses_builtin(spirv::BuiltIn::InstanceIndex).unwrap());
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert!(!ast.uses_builtin(spirv::BuiltIn::VertexIndex).unwrap());
+}
+
+#[test]
+fn ast_detects_ray_tracing_stage() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/raygen.asm.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    assert!(ast.is_ray_tracing_stage().unwrap());
+    assert_eq!(
+        ast.get_entry_points().unwrap()[0].execution_model,
+        spirv::ExecutionModel::RayGenerationKhr
+    );
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    assert!(!ast.is_ray_tracing_stage().unwrap());
+}
+
+#[test]
+fn ast_detects_physical_storage_buffer() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/physical_storage_buffer.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    assert!(ast
+        .get_declared_capabilities()
+        .unwrap()
+        .contains(&spirv::Capability::PhysicalStorageBufferAddresses));
+    assert!(ast.uses_physical_storage_buffer().unwrap());
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    assert_eq!(
+        ast.get_declared_capabilities().unwrap(),
+        vec![spirv::Capability::Shader]
+    );
+    assert!(!ast.uses_physical_storage_buffer().unwrap());
+}
+
+#[test]
+fn module_from_words_limited_checks_limits() {
+    let words = words_from_bytes(include_bytes!("shaders/simple.vert.spv"));
+    let id_bound = words[3];
+
+    let limits = spirv::ParseLimits {
+        max_id_bound: id_bound,
+        max_word_count: words.len(),
+    };
+    let module = spirv::Module::from_words_limited(words, limits).unwrap();
+    assert!(spirv::Ast::<lang::Target>::parse(&module).is_ok());
+
+    let limits = spirv::ParseLimits {
+        max_id_bound: id_bound - 1,
+        ..Default::default()
+    };
+    match spirv::Module::from_words_limited(words, limits) {
+        Err(spirv_cross::ErrorCode::CompilationError(message)) => {
+            assert!(message.contains("max_id_bound"))
+        }
+        _ => panic!("expected `max_id_bound` to be exceeded"),
+    }
+
+    let limits = spirv::ParseLimits {
+        max_word_count: words.len() - 1,
+        ..Default::default()
+    };
+    match spirv::Module::from_words_limited(words, limits) {
+        Err(spirv_cross::ErrorCode::CompilationError(message)) => {
+            assert!(message.contains("max_word_count"))
+        }
+        _ => panic!("expected `max_word_count` to be exceeded"),
+    }
+}
+
+#[test]
+fn module_validates() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    assert_eq!(module.validate().unwrap(), vec![]);
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/library.asm.spv")));
+    assert_eq!(
+        module.validate().unwrap(),
+        vec![
+            spirv::ValidationWarning::NoEntryPoints,
+            spirv::ValidationWarning::PhysicalAddressingModel,
+        ]
+    );
+
+    // An instruction cannot have a word count of zero.
+    let mut words = words_from_bytes(include_bytes!("shaders/simple.vert.spv"))[..5].to_vec();
+    words.push(0);
+    let module = spirv::Module::from_words(&words);
+    assert!(module.validate().is_err());
+}
+
+#[test]
+fn owned_module_parses() {
+    let bytes = include_bytes!("shaders/simple.vert.spv");
+    let module = spirv::OwnedModule::from_bytes(bytes).unwrap();
+    assert_eq!(module.words(), words_from_bytes(bytes));
+
+    let ast = spirv::Ast::<lang::Target>::parse(&module.as_module()).unwrap();
+    assert_eq!(ast.get_entry_points().unwrap()[0].name, "main");
+
+    let swapped = bytes
+        .chunks_exact(4)
+        .flat_map(|chunk| chunk.iter().rev().copied())
+        .collect::<Vec<_>>();
+    let swapped_module = spirv::OwnedModule::from_bytes(&swapped).unwrap();
+    assert_eq!(swapped_module, module);
+
+    assert!(spirv::OwnedModule::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn shader_resources_sorted_by_binding() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let resources = ast.get_shader_resources().unwrap();
+    let image_id = resources.separate_images[0].id;
+    let sampler_id = resources.separate_samplers[0].id;
+
+    ast.set_binding(image_id, 5).unwrap();
+    let sorted = resources
+        .sorted_by_binding(&ast)
+        .unwrap()
+        .into_iter()
+        .map(|(resource, set, binding)| (resource.id, set, binding))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sorted,
+        vec![(sampler_id, 0, Some(1)), (image_id, 0, Some(5))]
+    );
+
+    ast.unset_decoration(sampler_id, spirv::Decoration::Binding)
+        .unwrap();
+    let sorted = resources
+        .sorted_by_binding(&ast)
+        .unwrap()
+        .into_iter()
+        .map(|(resource, set, binding)| (resource.id, set, binding))
+        .collect::<Vec<_>>();
+    assert_eq!(sorted, vec![(image_id, 0, Some(5)), (sampler_id, 0, None)]);
+
+    // Unbound resources keep their original order rather than being sorted by set
+    ast.unset_decoration(image_id, spirv::Decoration::Binding)
+        .unwrap();
+    ast.set_descriptor_set(image_id, 1).unwrap();
+    let sorted = resources
+        .sorted_by_binding(&ast)
+        .unwrap()
+        .into_iter()
+        .map(|(resource, set, binding)| (resource.id, set, binding))
+        .collect::<Vec<_>>();
+    assert_eq!(sorted, vec![(image_id, 1, None), (sampler_id, 0, None)]);
+}
+
+#[test]
+fn ast_gets_used_descriptor_sets() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let sampler_id = ast.get_shader_resources().unwrap().separate_samplers[0].id;
+    ast.set_descriptor_set(sampler_id, 3).unwrap();
+    assert_eq!(
+        ast.used_descriptor_sets()
+            .unwrap()
+            .into_iter()
+            .collect::<Vec<_>>(),
+        vec![0, 3]
+    );
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/push_constant.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert!(ast.used_descriptor_sets().unwrap().is_empty());
+}
+
+#[test]
+fn ast_gets_descriptor_set_summary() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let sampler_id = ast.get_shader_resources().unwrap().separate_samplers[0].id;
+    ast.set_descriptor_set(sampler_id, 2).unwrap();
+
+    let summary = ast.descriptor_set_summary().unwrap();
+    assert_eq!(summary.keys().copied().collect::<Vec<_>>(), vec![0, 2]);
+    assert_eq!(
+        summary[&0],
+        spirv::DescriptorCounts {
+            separate_images: 1,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        summary[&2],
+        spirv::DescriptorCounts {
+            separate_samplers: 1,
+            ..Default::default()
+        }
+    );
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/texture_fixed_array.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(ast.descriptor_set_summary().unwrap()[&0].separate_images, 4);
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/texture_array.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(ast.descriptor_set_summary().unwrap()[&0].separate_images, 1);
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/acceleration_structure.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.descriptor_set_summary().unwrap()[&0].acceleration_structures,
+        2
+    );
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/texel_buffer.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.descriptor_set_summary().unwrap()[&0],
+        spirv::DescriptorCounts {
+            uniform_texel_buffers: 1,
+            ..Default::default()
+        }
+    );
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/storage_texel_buffer.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.descriptor_set_summary().unwrap()[&0],
+        spirv::DescriptorCounts {
+            uniform_texel_buffers: 1,
+            storage_texel_buffers: 1,
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn image_types_can_be_deduplicated() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/texture_array.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let separate_images = ast.get_shader_resources().unwrap().separate_images;
+    let image_types = [separate_images[0].base_type_id, 20]
+        .iter()
+        .map(|&id| match ast.get_type(id).unwrap() {
+            spirv::Type::Image { image, .. } | spirv::Type::SampledImage { image, .. } => image,
+            _ => panic!("type {} is not an image", id),
+        })
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(image_types.len(), 1);
+}
+
+#[test]
+fn ast_gets_resources_with_bindings() {
+    use spirv::ResourceKind::{StageInput, StageOutput, UniformBuffer};
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let infos = ast.resources_with_bindings().unwrap();
+    let summary = infos
+        .iter()
+        .map(|info| {
+            (
+                info.resource.name.as_str(),
+                info.kind,
+                info.set,
+                info.binding,
+                info.location,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            ("uniform_buffer_object", UniformBuffer, Some(0), None, None),
+            ("a_position", StageInput, None, None, Some(0)),
+            ("a_normal", StageInput, None, None, Some(1)),
+            ("v_normal", StageOutput, None, None, Some(0)),
+        ]
+    );
+}
+
+#[test]
+fn ast_gets_fragment_depth_mode() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/depth_layout.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_fragment_depth_mode().unwrap(),
+        spirv::FragmentDepthMode {
+            early_fragment_tests: true,
+            depth_replacing: true,
+            depth_layout: Some(spirv::DepthLayout::Greater),
+        }
+    );
+
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/mrt.asm.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_fragment_depth_mode().unwrap(),
+        spirv::FragmentDepthMode::default()
+    );
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert!(ast.get_fragment_depth_mode().is_err());
+}
+
+#[test]
+fn ast_gets_geometry_info() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/geometry.asm.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    assert_eq!(
+        ast.get_geometry_info().unwrap(),
+        spirv::GeometryInfo {
+            input_primitive: Some(spirv::GeometryInputPrimitive::Triangles),
+            output_primitive: Some(spirv::GeometryOutputPrimitive::TriangleStrip),
+            output_vertices: 3,
+            invocations: 2,
+        }
+    );
+    assert!(ast.get_tessellation_info().is_err());
+}
+
+#[test]
+fn ast_gets_tessellation_info() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/tessellation.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    assert_eq!(
+        ast.get_tessellation_info().unwrap(),
+        spirv::TessellationInfo {
+            primitive: Some(spirv::TessellationPrimitive::Quads),
+            spacing: Some(spirv::TessellationSpacing::FractionalOdd),
+            winding: Some(spirv::TessellationWinding::CounterClockwise),
+            point_mode: false,
+            output_vertices: None,
+        }
+    );
+    assert!(ast.get_geometry_info().is_err());
+}
+
+#[test]
+fn ast_gets_constants() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/mrt.asm.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let constants = ast.get_constants().unwrap();
+    assert_eq!(constants.len(), 4);
+
+    let mut scalars = constants
+        .iter()
+        .filter_map(|&id| ast.get_constant_value(id).ok())
+        .collect::<Vec<_>>();
+    scalars.sort_unstable();
+    assert_eq!(scalars, vec![0, u64::from(1.0f32.to_bits())]);
 }
 
 #[test]
-fn ast_gets_decoration() {
+fn ast_gets_source_file_name() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/line_directives.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(
+        ast.get_source_file_name().unwrap(),
+        Some(String::from("line_directives.frag"))
+    );
+
     let module =
         spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
     let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    assert_eq!(ast.get_source_file_name().unwrap(), None);
+}
 
-    let stage_inputs = ast.get_shader_resources().unwrap().stage_inputs;
-    let decoration = ast
-        .get_decoration(stage_inputs[0].id, spirv::Decoration::DescriptorSet)
-        .unwrap();
-    assert_eq!(decoration, 0);
+#[test]
+fn ast_gets_ids_for_type() {
+    use spirv::IdType;
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/library.asm.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    assert_eq!(ast.ids_for_type(IdType::Function).unwrap(), vec![1]);
+    assert_eq!(ast.ids_for_type(IdType::Type).unwrap(), vec![2]);
+    assert_eq!(ast.ids_for_type(IdType::FunctionPrototype).unwrap(), vec![3]);
+    assert_eq!(ast.ids_for_type(IdType::Block).unwrap(), vec![4]);
+    assert_eq!(ast.ids_for_type(IdType::Variable).unwrap(), vec![]);
 }
 
 #[test]
-fn ast_sets_decoration() {
+fn ast_gets_sampled_image_comparison() {
     let module =
-        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
-    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/shadow.asm.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
 
-    let stage_inputs = ast.get_shader_resources().unwrap().stage_inputs;
-    let updated_value = 3;
-    ast.set_decoration(
-        stage_inputs[0].id,
-        spirv::Decoration::DescriptorSet,
-        updated_value,
-    )
-    .unwrap();
-    assert_eq!(
-        ast.get_decoration(stage_inputs[0].id, spirv::Decoration::DescriptorSet)
-            .unwrap(),
-        updated_value
-    );
+    let mut comparisons = Vec::new();
+    for id in ast.ids_for_type(spirv::IdType::Type).unwrap() {
+        if let spirv::Type::SampledImage {
+            image, comparison, ..
+        } = ast.get_type(id).unwrap()
+        {
+            assert_eq!(comparison, image.depth);
+            comparisons.push(comparison);
+        }
+    }
+    comparisons.sort();
+
+    assert_eq!(comparisons, vec![false, true]);
 }
 
 #[test]
@@ -116,6 +1074,100 @@ fn ast_gets_type_member_types_and_array() {
     assert!(is_struct);
 }
 
+#[test]
+fn ast_gets_image_type() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let shader_resources = ast.get_shader_resources().unwrap();
+    let image = ast
+        .get_image_type(shader_resources.separate_images[0].base_type_id)
+        .unwrap();
+    assert_eq!(image.dim, spirv::Dim::Dim2D);
+    assert!(!image.depth);
+    assert!(!image.arrayed);
+    assert!(!image.ms);
+    assert_eq!(image.sampled, 1);
+    assert_eq!(image.format, spirv::ImageFormat::Unknown);
+
+    assert!(ast
+        .get_image_type(shader_resources.separate_samplers[0].base_type_id)
+        .is_err());
+}
+
+#[test]
+fn ast_gets_acceleration_structure_type() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/acceleration_structure.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let acceleration_structures = ast.get_shader_resources().unwrap().acceleration_structures;
+    assert_eq!(acceleration_structures.len(), 1);
+    assert_eq!(
+        ast.get_type(acceleration_structures[0].type_id).unwrap(),
+        spirv::Type::AccelerationStructure {
+            array: vec![2],
+            array_size_literal: vec![true],
+        }
+    );
+}
+
+#[test]
+fn ast_gets_physical_pointer_type() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/physical_storage_buffer.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let push_constant_buffers = ast.get_shader_resources().unwrap().push_constant_buffers;
+    let member_types = match ast.get_type(push_constant_buffers[0].base_type_id).unwrap() {
+        spirv::Type::Struct { member_types, .. } => member_types,
+        _ => panic!("push constant block is not a struct"),
+    };
+
+    let pointee_type = match ast.get_type(member_types[0]).unwrap() {
+        spirv::Type::Pointer {
+            pointee_type,
+            storage_class,
+            ..
+        } => {
+            assert_eq!(storage_class, spirv::StorageClass::PhysicalStorageBuffer);
+            pointee_type
+        }
+        _ => panic!("member is not a pointer"),
+    };
+    match ast.get_type(pointee_type).unwrap() {
+        spirv::Type::Struct { member_types, .. } => assert_eq!(member_types.len(), 1),
+        _ => panic!("pointee is not a struct"),
+    }
+}
+
+#[test]
+fn ast_gets_storage_class() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let shader_resources = ast.get_shader_resources().unwrap();
+    assert_eq!(
+        ast.get_storage_class(shader_resources.uniform_buffers[0].type_id)
+            .unwrap(),
+        spirv::StorageClass::Uniform
+    );
+    assert_eq!(
+        ast.get_storage_class(shader_resources.stage_inputs[0].type_id)
+            .unwrap(),
+        spirv::StorageClass::Input
+    );
+    assert_eq!(
+        ast.get_storage_class(shader_resources.stage_outputs[0].type_id)
+            .unwrap(),
+        spirv::StorageClass::Output
+    );
+}
+
 #[test]
 fn ast_gets_array_dimensions() {
     let module =
@@ -129,11 +1181,13 @@ fn ast_gets_array_dimensions() {
             assert_eq!(member_types.len(), 3);
             let is_float = match ast.get_type(member_types[2]).unwrap() {
                 spirv::Type::Float {
+                    width,
                     vecsize,
                     columns,
                     array,
                     array_size_literal
                 } => {
+                    assert_eq!(width, 32);
                     assert_eq!(vecsize, 3);
                     assert_eq!(columns, 1);
                     assert_eq!(array.len(), 1);
@@ -153,6 +1207,21 @@ fn ast_gets_array_dimensions() {
     assert!(is_struct);
 }
 
+#[test]
+fn ast_gets_member_count() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let shader_resources = ast.get_shader_resources().unwrap();
+    assert_eq!(
+        ast.get_member_count(shader_resources.uniform_buffers[0].base_type_id),
+        Ok(2)
+    );
+    assert!(ast
+        .get_member_count(shader_resources.stage_inputs[0].base_type_id)
+        .is_err());
+}
+
 #[test]
 fn ast_gets_declared_struct_size_and_struct_member_size() {
     let module =
@@ -178,6 +1247,79 @@ fn ast_gets_declared_struct_size_and_struct_member_size() {
     );
 }
 
+#[test]
+fn ast_gets_declared_struct_size_runtime() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/runtime_array.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let storage_buffers = ast.get_shader_resources().unwrap().storage_buffers;
+    let id = storage_buffers[0].base_type_id;
+
+    let uint_size = 4;
+    assert_eq!(ast.get_declared_struct_size_runtime(id, 0).unwrap(), 0);
+    assert_eq!(
+        ast.get_declared_struct_size_runtime(id, 10).unwrap(),
+        10 * uint_size
+    );
+}
+
+#[test]
+fn ast_gets_member_layout() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/array.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let uniform_buffers = ast.get_shader_resources().unwrap().uniform_buffers;
+    let struct_id = uniform_buffers[0].base_type_id;
+
+    assert_eq!(
+        ast.get_member_layout(struct_id, 0).unwrap(),
+        spirv::MemberLayout {
+            offset: 0,
+            size: 64,
+            array_stride: None,
+            matrix_stride: Some(16),
+            row_major: false,
+        }
+    );
+    assert_eq!(
+        ast.get_member_layout(struct_id, 1).unwrap(),
+        spirv::MemberLayout {
+            offset: 64,
+            size: 4,
+            array_stride: None,
+            matrix_stride: None,
+            row_major: false,
+        }
+    );
+    assert_eq!(
+        ast.get_member_layout(struct_id, 2).unwrap(),
+        spirv::MemberLayout {
+            offset: 80,
+            size: 48,
+            array_stride: Some(16),
+            matrix_stride: None,
+            row_major: false,
+        }
+    );
+}
+
+#[test]
+fn ast_gets_row_major_members() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/row_major.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let uniform_buffers = ast.get_shader_resources().unwrap().uniform_buffers;
+    let struct_id = uniform_buffers[0].base_type_id;
+
+    assert_eq!(ast.is_row_major(struct_id, 0), Ok(false));
+    assert_eq!(ast.is_row_major(struct_id, 1), Ok(true));
+    assert!(ast.get_member_layout(struct_id, 1).unwrap().row_major);
+}
+
 #[test]
 fn ast_gets_member_name() {
     let module =
@@ -193,6 +1335,68 @@ fn ast_gets_member_name() {
     );
 }
 
+#[test]
+fn ast_gets_names_without_allocating_when_undefined() {
+    use std::borrow::Cow;
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let uniform_buffers = ast.get_shader_resources().unwrap().uniform_buffers;
+    let spirv::Resource {
+        id, base_type_id, ..
+    } = uniform_buffers[0];
+
+    assert!(matches!(ast.get_name_cow(id).unwrap(), Cow::Borrowed("")));
+    assert_eq!(
+        ast.get_name_cow(base_type_id).unwrap(),
+        "uniform_buffer_object"
+    );
+    assert_eq!(
+        ast.get_member_name_cow(base_type_id, 0).unwrap(),
+        "u_model_view_projection"
+    );
+    assert!(matches!(
+        ast.get_member_name_cow(base_type_id, 5).unwrap(),
+        Cow::Borrowed("")
+    ));
+}
+
+#[test]
+fn ast_sets_member_name_checked() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let uniform_buffers = ast.get_shader_resources().unwrap().uniform_buffers;
+    let id = uniform_buffers[0].base_type_id;
+
+    assert!(ast
+        .set_member_name_checked(id, 1, "u_model_view_projection")
+        .is_err());
+    assert!(ast.set_member_name_checked(id, 1, "1scale").is_err());
+    assert!(ast.set_member_name_checked(id, 1, "gl_scale").is_err());
+    assert!(ast.set_member_name_checked(id, 2, "scale").is_err());
+    assert!(ast.set_member_name_checked(id, 1, "float").is_err());
+    assert!(ast.set_member_name_checked(id, 1, "float4x4").is_err());
+    assert!(ast.set_member_name_checked(id, 1, "cbuffer").is_err());
+    assert!(ast.set_member_name_checked(id, 1, "texture").is_err());
+    assert!(ast.set_member_name_checked(id, 1, "vec4").is_err());
+    assert!(matches!(
+        ast.set_member_name_checked(id, 1, "sampler"),
+        Err(spirv_cross::ErrorCode::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        ast.set_member_name_checked(id, 9, "scale"),
+        Err(spirv_cross::ErrorCode::InvalidArgument(_))
+    ));
+    assert_eq!(ast.get_member_name(id, 1).unwrap(), "u_scale");
+
+    ast.set_member_name_checked(id, 1, "scale").unwrap();
+    assert_eq!(ast.get_member_name(id, 1).unwrap(), "scale");
+}
+
 #[test]
 fn ast_gets_member_decoration() {
     let module =
@@ -251,6 +1455,57 @@ fn ast_gets_specialization_constants() {
     assert_eq!(specialization_constants[0].constant_id, 10);
 }
 
+#[test]
+fn ast_gets_specialization_constant_usages() {
+    let comp = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/spec_array_size.asm.spv"
+    )));
+    let comp_ast = spirv::Ast::<lang::Target>::parse(&comp).unwrap();
+    assert_eq!(
+        comp_ast.get_specialization_constant_usages().unwrap(),
+        [
+            (
+                spirv::SpecializationConstant {
+                    id: 6,
+                    constant_id: 0
+                },
+                spirv::SpecializationConstantUsage::WorkGroupSize(0)
+            ),
+            (
+                spirv::SpecializationConstant {
+                    id: 7,
+                    constant_id: 3
+                },
+                spirv::SpecializationConstantUsage::ArraySize(13)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn ast_sets_composite_constant() {
+    let comp = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/spec_workgroup_size.asm.spv"
+    )));
+    let mut comp_ast = spirv::Ast::<lang::Target>::parse(&comp).unwrap();
+    let work_group_size_id = 11;
+    comp_ast
+        .set_composite_constant(work_group_size_id, &[8, 4, 2])
+        .unwrap();
+
+    let work_group_size = comp_ast
+        .get_work_group_size_specialization_constants()
+        .unwrap();
+    assert_eq!(comp_ast.get_constant_value(work_group_size.x.id), Ok(8));
+    assert_eq!(comp_ast.get_constant_value(work_group_size.y.id), Ok(4));
+    assert_eq!(comp_ast.get_constant_value(work_group_size.z.id), Ok(2));
+
+    assert!(matches!(
+        comp_ast.set_composite_constant(work_group_size_id, &[8, 4]),
+        Err(spirv_cross::ErrorCode::CompilationError(_))
+    ));
+}
+
 #[test]
 fn ast_gets_work_group_size_specialization_constants() {
     let comp = spirv::Module::from_words(words_from_bytes(include_bytes!(
@@ -332,3 +1587,167 @@ fn ast_gets_active_buffer_ranges() {
         ]
     );
 }
+
+#[test]
+fn ast_gets_id_bound() {
+    let module = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/vs_and_fs.asm.spv"
+    )));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    assert_eq!(ast.get_id_bound().unwrap(), 25);
+}
+
+#[test]
+fn validates_matching_stage_interface() {
+    let vert =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/struct.vert.spv")));
+    let frag =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/struct.frag.spv")));
+    let vert_ast = spirv::Ast::<lang::Target>::parse(&vert).unwrap();
+    let frag_ast = spirv::Ast::<lang::Target>::parse(&frag).unwrap();
+
+    assert_eq!(
+        spirv::validate_stage_interface(&vert_ast, &frag_ast),
+        Ok(())
+    );
+}
+
+#[test]
+fn validates_mismatched_stage_interface() {
+    let vert =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let frag =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let vert_ast = spirv::Ast::<lang::Target>::parse(&vert).unwrap();
+    let frag_ast = spirv::Ast::<lang::Target>::parse(&frag).unwrap();
+
+    match spirv::validate_stage_interface(&vert_ast, &frag_ast) {
+        Err(spirv::InterfaceMismatch::TypeMismatch {
+            location,
+            output_type,
+            input_type,
+        }) => {
+            assert_eq!(location, 0);
+            match output_type {
+                spirv::Type::Float { vecsize, .. } => assert_eq!(vecsize, 3),
+                _ => panic!("unexpected output type"),
+            }
+            match input_type {
+                spirv::Type::Float { vecsize, .. } => assert_eq!(vecsize, 2),
+                _ => panic!("unexpected input type"),
+            }
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn validates_missing_stage_output() {
+    let vert = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/const_array.vert.spv"
+    )));
+    let frag =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let vert_ast = spirv::Ast::<lang::Target>::parse(&vert).unwrap();
+    let frag_ast = spirv::Ast::<lang::Target>::parse(&frag).unwrap();
+
+    match spirv::validate_stage_interface(&vert_ast, &frag_ast) {
+        Err(spirv::InterfaceMismatch::MissingOutput { location, .. }) => assert_eq!(location, 0),
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn validates_missing_stage_interface_location() {
+    let vert = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/unlocated_output.asm.spv"
+    )));
+    let frag =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/sampler.frag.spv")));
+    let vert_ast = spirv::Ast::<lang::Target>::parse(&vert).unwrap();
+    let frag_ast = spirv::Ast::<lang::Target>::parse(&frag).unwrap();
+
+    let mismatch = spirv::validate_stage_interface(&vert_ast, &frag_ast).unwrap_err();
+    assert_eq!(
+        mismatch,
+        spirv::InterfaceMismatch::MissingOutputLocation {
+            name: String::from("v_uv")
+        }
+    );
+    assert_eq!(
+        mismatch.to_string(),
+        "producer output `v_uv` has no location"
+    );
+}
+
+#[test]
+fn validates_stage_interface_with_specialized_array_size() {
+    let vert = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/spec_array_output.asm.spv"
+    )));
+    let frag = spirv::Module::from_words(words_from_bytes(include_bytes!(
+        "shaders/array_input.asm.spv"
+    )));
+    let mut vert_ast = spirv::Ast::<lang::Target>::parse(&vert).unwrap();
+    let frag_ast = spirv::Ast::<lang::Target>::parse(&frag).unwrap();
+
+    assert_eq!(
+        spirv::validate_stage_interface(&vert_ast, &frag_ast),
+        Ok(())
+    );
+
+    let weight_count = vert_ast.get_specialization_constants().unwrap()[0].id;
+    vert_ast.set_scalar_constant(weight_count, 3).unwrap();
+    let mismatch = spirv::validate_stage_interface(&vert_ast, &frag_ast).unwrap_err();
+    assert!(matches!(
+        mismatch,
+        spirv::InterfaceMismatch::TypeMismatch { location: 0, .. }
+    ));
+    assert!(mismatch
+        .to_string()
+        .starts_with("location 0: producer writes Float {"));
+}
+
+#[test]
+fn error_code_from_raw_result() {
+    use spirv_cross::{ErrorCode, ScInternalResult};
+
+    assert_eq!(ErrorCode::from_raw(ScInternalResult::Success), Ok(()));
+    assert_eq!(
+        ErrorCode::from_raw(ScInternalResult::Unhandled),
+        Err(ErrorCode::Unhandled)
+    );
+}
+
+#[test]
+fn error_code_chains_source() {
+    use std::error::Error;
+
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/simple.vert.spv")));
+    let mut ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+
+    let error = ast.set_name(1, "a\0b").unwrap_err();
+    match &error {
+        spirv_cross::ErrorCode::Nul(nul_error) => assert_eq!(nul_error.nul_position(), 1),
+        _ => panic!("expected a nul error"),
+    }
+    assert!(error.source().is_some());
+    assert!(spirv_cross::ErrorCode::Unhandled.source().is_none());
+}
+
+#[cfg(feature = "debug-ir")]
+#[test]
+fn ast_dumps_ir() {
+    let module =
+        spirv::Module::from_words(words_from_bytes(include_bytes!("shaders/two_ubo.vert.spv")));
+    let ast = spirv::Ast::<lang::Target>::parse(&module).unwrap();
+    let dump = ast.dump_ir().unwrap();
+
+    assert!(dump.contains("Type \"ubo1\""));
+    assert!(dump.contains("member 1 \"b\""));
+    assert!(dump.contains("Offset = 64"));
+    assert!(dump.contains("= Variable : %"));
+    assert_eq!(dump, ast.dump_ir().unwrap());
+}